@@ -20,6 +20,7 @@ use proc_macro2::{Ident, Span, TokenStream as TS2};
 use quote::ToTokens;
 use std::{boxed::Box, str::FromStr};
 use syn::{
+    parse::{Parse, ParseStream},
     parse_macro_input, parse_quote,
     punctuated::Punctuated,
     token::{Colon, Eq, Let, Semi},
@@ -180,6 +181,10 @@ fn generic_arguments(pargs: &PathArguments) -> Vec<GenericArgument> {
 
 /**
 I write Rust idiomatic interface for CSPICE.
+
+With the attribute [`macro@fallible`], a second function `try_<name>` is generated along with the
+wrapper, which checks the SPICE error status after the call to CSPICE and returns a `Result`
+instead. It is also exposed as a method of the `SpiceLock` if the wrapper is.
 */
 #[proc_macro]
 pub fn cspice_proc(input: TokenStream) -> TokenStream {
@@ -194,6 +199,7 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
     let generics = sig.generics;
 
     let return_output = attrs.iter().any(|attr| tts!(attr.path) == "return_output");
+    let fallible = attrs.iter().any(|attr| tts!(attr.path) == "fallible");
 
     let semi_call = semi(!return_output);
 
@@ -223,6 +229,9 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
         })
         .collect::<Punctuated<_, Token![,]>>();
 
+    // Strings are converted to C strings that must live until the end of the call to CSPICE.
+    let mut vars_in_decl = Vec::<Local>::new();
+
     // Build CSPICE inputs from function inputs and reference to function outputs.
     let mut cspice_inputs = Punctuated::<Pat, Token![,]>::new();
     // Function inpus into CSPICE inputs.
@@ -236,21 +245,45 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
 
                 match ty {
                     Type::Path(tp) => match path_get_last_s_ident(&tp).0.as_str() {
-                        "String" => pat_macro("crate::cstr", &ident),
+                        "String" => {
+                            vars_in_decl.push(declare(
+                                format!("mut {}", ident),
+                                Some(tts!(pat_macro("crate::cstr", &ident))),
+                            ));
+                            new_pat(format!("{}.as_mut_ptr()", ident))
+                        }
                         "f64" | "i32" => new_pat(ident),
-                        "usize" => new_pat(format!("{} as i32", ident)),
+                        "usize" | "bool" => new_pat(format!("{} as i32", ident)),
                         "DLADSC" => new_pat(format!("&mut {}", ident)),
                         _ => panic!("->1"),
                     },
                     Type::Reference(tr) => match *tr.elem {
                         Type::Path(tp) => match path_get_last_s_ident(&tp).0.as_str() {
-                            "str" => pat_macro("crate::cstr", &format!("{}.to_string()", ident)),
+                            "str" => {
+                                vars_in_decl.push(declare(
+                                    format!("mut {}", ident),
+                                    Some(tts!(pat_macro("crate::cstr", &ident))),
+                                ));
+                                new_pat(format!("{}.as_mut_ptr()", ident))
+                            }
+                            // CSPICE takes windows as mutable even when only reading them.
+                            "Window" => {
+                                vars_in_decl.push(declare(
+                                    format!("mut {}", ident),
+                                    Some(format!("{}.clone()", ident)),
+                                ));
+                                new_pat(format!("{}.as_mut_ptr()", ident))
+                            }
                             _ => panic!("->2"),
                         },
                         Type::Slice(_) => new_pat(format!("{}.as_mut_ptr()", ident)),
                         _ => panic!("->3"),
                     },
                     Type::Array(_) => new_pat(format!("{}.as_mut_ptr()", ident)),
+                    // Epochs are taken as `impl Into<Et>` and sent as TDB seconds past J2000.
+                    Type::ImplTrait(ti) if tts!(ti).replace(' ', "") == "implInto<Et>" => {
+                        new_pat(format!("Into::<crate::Et>::into({}).0", ident))
+                    }
                     _ => panic!("->4"),
                 }
             }
@@ -262,6 +295,13 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
     let mut vars_out_decl = Vec::<Local>::new();
     let mut vars_out = Vec::<Pat>::new();
     let mut out_is_bool = false;
+    // Output cell or window, filled again with a larger capacity if CSPICE signals it is too small.
+    let mut out_cell = None;
+    // GF searches fill their result from workspace windows of `nintvls` intervals.
+    let has_nintvls = sig.inputs.iter().any(|arg| match arg {
+        FnArg::Typed(pt) => tts!(pt.pat) == "nintvls",
+        FnArg::Receiver(_) => false,
+    });
     // Get function ouputs
     let output = match sig.output {
         ReturnType::Type(_, ty) => {
@@ -293,10 +333,10 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
                                 "String" => {
                                     let ident = format!("varout_{}", vars_out_decl.len());
                                     vars_out_decl.push(declare(
-                                        &ident,
-                                        Some(&"crate::mallocstr!(crate::MAX_LEN_OUT)".to_string()),
+                                        format!("mut {}", ident),
+                                        Some("crate::mallocstr!(crate::MAX_LEN_OUT)".to_string()),
                                     ));
-                                    cspice_inputs.push(pat_ident(ident.clone()));
+                                    cspice_inputs.push(new_pat(format!("{}.as_mut_ptr()", ident)));
                                     vars_out.push(new_pat(format!("crate::fcstr!({})", ident)));
                                 }
                                 "bool" => {
@@ -324,7 +364,12 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
                             let ident = format!("varout_{}", vars_out_decl.len());
                             let pat_ident_fc = new_pat(format!("{}.as_mut_ptr()", ident));
                             let size = array_get_size(ta);
-                            let init = format!("{:?}", vec![0.0f64; size]);
+                            let init = match *ta.elem.clone() {
+                                Type::Array(ta_2) => {
+                                    format!("{:?}", vec![vec![0.0f64; array_get_size(&ta_2)]; size])
+                                }
+                                _ => format!("{:?}", vec![0.0f64; size]),
+                            };
                             vars_out_decl.push(declare(format!("mut {}", ident), Some(init)));
                             cspice_inputs.push(pat_ident_fc);
                             vars_out.push(pat_ident(ident));
@@ -357,10 +402,10 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
                             "String" => {
                                 let ident = format!("varout_{}", vars_out_decl.len());
                                 vars_out_decl.push(declare(
-                                    &ident,
-                                    Some(&"mallocstr!(crate::MAX_LEN_OUT)".to_string()),
+                                    format!("mut {}", ident),
+                                    Some("crate::mallocstr!(crate::MAX_LEN_OUT)".to_string()),
                                 ));
-                                cspice_inputs.push(pat_ident(ident.clone()));
+                                cspice_inputs.push(new_pat(format!("{}.as_mut_ptr()", ident)));
                                 vars_out.push(new_pat(format!("crate::fcstr!({})", ident)));
                             }
                             "bool" => {
@@ -383,11 +428,32 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
                             }
                             "Cell" => {
                                 let ident = format!("varout_{}", vars_out_decl.len());
+                                let element = tts!(generic_arguments(&a.1)[0]);
                                 vars_out_decl.push(declare(
                                     format!("mut {}", ident),
-                                    Some("Cell::new_int()".to_string()),
+                                    Some(format!(
+                                        "Cell::<{}>::new(crate::core::cell::CELL_MAXID)",
+                                        element
+                                    )),
                                 ));
-                                cspice_inputs.push(new_pat(format!("&mut {}.0", ident)));
+                                cspice_inputs.push(new_pat(format!("{}.as_mut_ptr()", ident)));
+                                out_cell = Some(Ident::new(&ident, Span::call_site()));
+                                vars_out.push(new_pat(ident));
+                            }
+                            "Window" => {
+                                let ident = format!("varout_{}", vars_out_decl.len());
+                                let size = match has_nintvls {
+                                    true => {
+                                        "crate::core::cell::CELL_MAXID.max(nintvls.max(0) as usize)"
+                                    }
+                                    false => "crate::core::cell::CELL_MAXID",
+                                };
+                                vars_out_decl.push(declare(
+                                    format!("mut {}", ident),
+                                    Some(format!("Window::new({})", size)),
+                                ));
+                                cspice_inputs.push(new_pat(format!("{}.as_mut_ptr()", ident)));
+                                out_cell = Some(Ident::new(&ident, Span::call_site()));
                                 vars_out.push(new_pat(ident));
                             }
                             _ => panic!("->8"),
@@ -451,17 +517,72 @@ pub fn cspice_proc(input: TokenStream) -> TokenStream {
         },
     };
 
-    let tokens = quote! {
-        #(#attrs)*
-        #vis fn #fname#generics(#inputs) -> #output {
+    let body = match out_cell {
+        // The closure argument shadows the output, which the CSPICE inputs refer to.
+        Some(out_cell) => quote! {
+            #(#vars_in_decl)*
+            #(#vars_out_decl)*
+            crate::core::cell::fill(&mut #out_cell, |#out_cell| {
+                #[allow(unused_unsafe)]
+                unsafe {
+                    crate::c::#cspice_func(#cspice_inputs);
+                }
+            });
+            #function_output
+        },
+        None => quote! {
+            #(#vars_in_decl)*
             #(#vars_out_decl)*
             #[allow(unused_unsafe)]
             unsafe {
                 crate::c::#cspice_func(#cspice_inputs)#semi_call
                 #function_output
             }
+        },
+    };
+
+    let mut tokens = quote! {
+        #(#attrs)*
+        #vis fn #fname#generics(#inputs) -> #output {
+            #body
         }
     };
+    if fallible {
+        let try_fname = Ident::new(&format!("try_{}", fname), Span::call_site());
+        let doc = format!(
+            "Fallible version of [`{}`], returning the error signaled by CSPICE as a \
+            [`SpiceError`][crate::SpiceError] instead of aborting.",
+            fname
+        );
+        // Lints are kept, while the docs and the lock method are generated for the new name.
+        let lints = attrs.iter().filter(|attr| {
+            let path = tts!(attr.path);
+            path != "doc"
+                && path != "fallible"
+                && path != "return_output"
+                && !tts!(attr).contains("impl_for")
+        });
+        let impl_for = attrs
+            .iter()
+            .any(|attr| tts!(attr).contains("impl_for"))
+            .then(|| {
+                quote! {
+                    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = #try_fname))]
+                }
+            });
+        // Wrapping the output in a `Result` may make its type complex.
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #(#lints)*
+            #impl_for
+            #[allow(clippy::type_complexity)]
+            #vis fn #try_fname#generics(#inputs) -> crate::core::error::Result<#output> {
+                crate::core::error::try_call(|| {
+                    #body
+                })
+            }
+        });
+    }
     if [].contains(&fname.to_string().as_str()) {
         println!("{}", tokens);
     }
@@ -473,8 +594,47 @@ pub fn return_output(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+/**
+Make [`cspice_proc!`] also generate `try_<name>`, which checks the SPICE error status after the
+call to CSPICE, returning a `Result<Output, SpiceError>`.
+*/
 #[proc_macro_attribute]
-pub fn impl_for(struct_path: TokenStream, function: TokenStream) -> TokenStream {
+pub fn fallible(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+/**
+Arguments of [`macro@impl_for`]: the path of the struct, and optionally the name of the method.
+*/
+struct ImplFor {
+    struct_path: Path,
+    name: Option<Ident>,
+}
+
+impl Parse for ImplFor {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let struct_path = input.parse()?;
+        let name = if input.parse::<Option<Token![,]>>()?.is_some() {
+            let key = input.parse::<Ident>()?;
+            if key != "name" {
+                return Err(syn::Error::new(key.span(), "expected `name = method_name`"));
+            }
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { struct_path, name })
+    }
+}
+
+/**
+Also expose the function as a method of a struct, with the same name unless another one is given,
+as in `impl_for(SpiceLock, name = raw_timout)`.
+*/
+#[proc_macro_attribute]
+pub fn impl_for(args: TokenStream, function: TokenStream) -> TokenStream {
+    let ImplFor { struct_path, name } = parse_macro_input!(args as ImplFor);
     let function = parse_macro_input!(function as ItemFn);
 
     let Signature {
@@ -487,7 +647,7 @@ pub fn impl_for(struct_path: TokenStream, function: TokenStream) -> TokenStream
 
     let attrs = function.attrs.clone();
 
-    let new_fname = Ident::new(&fname.to_string(), Span::call_site());
+    let new_fname = name.unwrap_or_else(|| Ident::new(&fname.to_string(), Span::call_site()));
 
     // Retreive argument identifiers without types, mutability etc.
     let arg_idents = inputs
@@ -501,12 +661,26 @@ pub fn impl_for(struct_path: TokenStream, function: TokenStream) -> TokenStream
         })
         .collect::<Punctuated<Ident, Token![,]>>();
 
-    let struct_path = syn::parse::<Path>(struct_path).expect("Invalid struct path");
+    // Mutable parameters are only moved by the method
+    let method_inputs = inputs
+        .iter()
+        .cloned()
+        .map(|mut input| {
+            if let FnArg::Typed(PatType { pat, .. }) = &mut input {
+                if let Pat::Ident(PatIdent { mutability, .. }) = &mut **pat {
+                    *mutability = None;
+                }
+            }
+            input
+        })
+        .collect::<Punctuated<FnArg, Token![,]>>();
 
+    // The receiver adds a parameter, which may exceed the limit of clippy
     let impl_block = quote! {
         impl #struct_path {
             #(#attrs)*
-            pub fn #new_fname#generics(&self, #inputs)#output {
+            #[allow(clippy::too_many_arguments)]
+            pub fn #new_fname#generics(&self, #method_inputs)#output {
                 #fname(#arg_idents)
             }
         }
@@ -516,3 +690,82 @@ pub fn impl_for(struct_path: TokenStream, function: TokenStream) -> TokenStream
     out.extend(impl_block.to_token_stream());
     out.into()
 }
+
+/**
+Arguments of [`macro@with_lock`]: whether to also generate the unlocked function, and the
+parameter taking the lock.
+*/
+struct WithLock {
+    unlocked: bool,
+    lock_arg: FnArg,
+}
+
+impl Parse for WithLock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut unlocked = false;
+        if input.peek(syn::Ident) && !input.peek2(Token![:]) {
+            let key = input.parse::<Ident>()?;
+            if key != "unlocked" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "expected `unlocked` or the parameter taking the lock",
+                ));
+            }
+            unlocked = true;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        let lock_arg = match input.is_empty() {
+            true => parse_quote!(_lock: &crate::SpiceLock),
+            false => input.parse()?,
+        };
+        Ok(Self { unlocked, lock_arg })
+    }
+}
+
+/**
+With the `lock` feature, make the function take the `SpiceLock` as an additional parameter,
+right after the receiver if any, so that only the holder of the lock can call it.
+
+The parameter is `_lock: &crate::SpiceLock` unless another one is given, as in
+`with_lock(lock: &'l SpiceLock)` for a function keeping the lock borrowed. With
+`with_lock(unlocked)`, a crate-private `<name>_unlocked` function without the parameter is also
+generated, for the functions of the crate already called under the lock. Both can be combined, as
+in `with_lock(unlocked, _lock: &'l SpiceLock)`.
+*/
+#[proc_macro_attribute]
+pub fn with_lock(args: TokenStream, function: TokenStream) -> TokenStream {
+    let WithLock { unlocked, lock_arg } = parse_macro_input!(args as WithLock);
+    let function = parse_macro_input!(function as ItemFn);
+
+    let mut locked = function.clone();
+    let position = match locked.sig.inputs.first() {
+        Some(FnArg::Receiver(_)) => 1,
+        _ => 0,
+    };
+    locked.sig.inputs.insert(position, lock_arg);
+
+    let mut out = quote! {
+        #[cfg(not(feature = "lock"))]
+        #function
+        #[cfg(feature = "lock")]
+        #locked
+    };
+
+    if unlocked {
+        let mut unlocked = function;
+        unlocked.sig.ident = Ident::new(
+            &format!("{}_unlocked", unlocked.sig.ident),
+            Span::call_site(),
+        );
+        unlocked.vis = parse_quote!(pub(crate));
+        unlocked.attrs.retain(|attr| !attr.path.is_ident("doc"));
+        out.extend(quote! {
+            #[allow(dead_code)]
+            #unlocked
+        });
+    }
+
+    out.into()
+}
@@ -0,0 +1,594 @@
+/*!
+Typed SPICE cells and sets.
+
+## Description
+
+A [`Cell`] owns the storage of a CSPICE cell of integers, double precision numbers or fixed-length
+strings, and frees it when dropped. It can be indexed, iterated and converted from and into a
+[`Vec`].
+
+A cell whose elements are sorted and unique is a set. The set operations [`Cell::union`],
+[`Cell::intersection`] and [`Cell::difference`] accept any cell and validate a copy of their
+operands first, so the cells themselves are left untouched.
+
+The cells and windows returned by the wrappers, such as coverage windows or GF search results, start
+with a capacity of [`CELL_MAXID`] and are filled again with twice the capacity whenever CSPICE
+signals they are too small. The remaining limits are the workspaces of CSPICE: the `nintvls`
+argument of the GF searches taking one, and fixed sizes in CSPICE for the others.
+
+With the `lock` feature, the operations calling CSPICE, such as [`Cell::insert`] or the set
+operations, take the [`SpiceLock`][crate::SpiceLock] as an additional argument, and the cells are
+no longer built from a [`Vec`] or an iterator.
+
+See the C documentation about [cells][cells link] and [sets][sets link].
+
+[cells link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/cells.html
+[sets link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/sets.html
+*/
+
+use crate::core::error::{self, try_call};
+use crate::{c, cstr, malloc, mptr};
+use spice_derive::with_lock;
+use std::fmt;
+#[cfg(not(feature = "lock"))]
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::Index;
+use std::os::raw::c_char;
+
+/**
+Initial capacity of the cells returned by the wrappers. They are declared again with a larger
+capacity when CSPICE signals they are too small, see [`fill`].
+*/
+pub const CELL_MAXID: usize = 10_000;
+
+/**
+Short messages of the errors signaled by CSPICE when an output cell or window is too small.
+*/
+const EXCESS_ERRORS: [&str; 3] = [
+    "SPICE(CELLTOOSMALL)",
+    "SPICE(SETEXCESS)",
+    "SPICE(WINDOWEXCESS)",
+];
+
+/**
+Size of the control area at the beginning of the storage of a cell, in number of elements.
+*/
+const CTRLSZ: usize = c::SPICE_CELL_CTRLSZ as usize;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for i32 {}
+    impl Sealed for f64 {}
+    impl Sealed for String {}
+}
+
+/**
+Type of the elements of a [`Cell`]: [`i32`], [`f64`] or [`String`].
+
+This trait is sealed and cannot be implemented outside of **rust-spice**.
+*/
+pub trait CellElement: private::Sealed + Sized {
+    /**
+    Borrowed form of an element, returned when indexing or iterating over a cell.
+    */
+    type Item: ?Sized + ToOwned<Owned = Self>;
+
+    #[doc(hidden)]
+    type Raw: Copy + Default;
+
+    #[doc(hidden)]
+    const DTYPE: c::_SpiceDataType;
+
+    #[doc(hidden)]
+    fn length(items: &[Self]) -> usize;
+
+    #[doc(hidden)]
+    fn read(slot: &[Self::Raw]) -> &Self::Item;
+
+    #[doc(hidden)]
+    fn append(self, cell: &mut c::SpiceCell);
+
+    #[doc(hidden)]
+    fn insert(self, cell: &mut c::SpiceCell);
+}
+
+macro_rules! impl_numeric_element {
+    ($t:ty, $dtype:expr, $appnd:ident, $insrt:ident) => {
+        impl CellElement for $t {
+            type Item = $t;
+            type Raw = $t;
+
+            const DTYPE: c::_SpiceDataType = $dtype;
+
+            fn length(_items: &[Self]) -> usize {
+                0
+            }
+
+            fn read(slot: &[Self::Raw]) -> &Self::Item {
+                &slot[0]
+            }
+
+            fn append(self, cell: &mut c::SpiceCell) {
+                unsafe { c::$appnd(self, cell) }
+            }
+
+            fn insert(self, cell: &mut c::SpiceCell) {
+                unsafe { c::$insrt(self, cell) }
+            }
+        }
+
+        impl Cell<$t> {
+            /**
+            Declare an empty cell able to hold up to `size` elements.
+            */
+            pub fn new(size: usize) -> Self {
+                Self::declare(size, 0)
+            }
+        }
+    };
+}
+
+impl_numeric_element!(i32, c::_SpiceDataType_SPICE_INT, appndi_c, insrti_c);
+impl_numeric_element!(f64, c::_SpiceDataType_SPICE_DP, appndd_c, insrtd_c);
+
+impl CellElement for String {
+    type Item = str;
+    type Raw = c_char;
+
+    const DTYPE: c::_SpiceDataType = c::_SpiceDataType_SPICE_CHR;
+
+    fn length(items: &[Self]) -> usize {
+        items.iter().map(String::len).max().unwrap_or(0) + 1
+    }
+
+    fn read(slot: &[Self::Raw]) -> &Self::Item {
+        let bytes = unsafe { std::slice::from_raw_parts(slot.as_ptr() as *const u8, slot.len()) };
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        // CSPICE truncates the strings by byte, so only a valid prefix is kept.
+        match std::str::from_utf8(&bytes[..end]) {
+            Ok(item) => item,
+            Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap(),
+        }
+    }
+
+    fn append(self, cell: &mut c::SpiceCell) {
+        let mut item = cstr!(fit(self, cell.length));
+        unsafe { c::appndc_c(mptr!(item), cell) }
+    }
+
+    fn insert(self, cell: &mut c::SpiceCell) {
+        let mut item = cstr!(fit(self, cell.length));
+        unsafe { c::insrtc_c(mptr!(item), cell) }
+    }
+}
+
+/**
+Truncate a string to the longest prefix fitting in `length` characters, null terminator included,
+without splitting a multibyte character as CSPICE would.
+*/
+fn fit(mut item: String, length: i32) -> String {
+    let mut end = (length.max(1) as usize - 1).min(item.len());
+    while !item.is_char_boundary(end) {
+        end -= 1;
+    }
+    item.truncate(end);
+    item
+}
+
+impl Cell<String> {
+    /**
+    Declare an empty cell able to hold up to `size` strings of `length` characters, null
+    terminator included.
+    */
+    pub fn new(size: usize, length: usize) -> Self {
+        Self::declare(size, length)
+    }
+}
+
+/**
+A cell is a data structure intended to provide safe array access within the applications.
+
+The elements are stored in a buffer owned by the cell, which is freed when the cell is dropped.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/cells.html).
+*/
+pub struct Cell<T: CellElement> {
+    cell: c::SpiceCell,
+    // Storage pointed to by the cell, control area included.
+    buffer: Vec<T::Raw>,
+    _element: PhantomData<T>,
+}
+
+impl<T: CellElement> Cell<T> {
+    /**
+    Declare an empty cell of `size` elements of `length` characters, zero for numeric cells.
+    */
+    fn declare(size: usize, length: usize) -> Self {
+        let stride = length.max(1);
+        let mut buffer = malloc!(T::Raw, (CTRLSZ + size) * stride);
+        let base = mptr!(buffer);
+        Self {
+            cell: c::SpiceCell {
+                dtype: T::DTYPE,
+                length: length as i32,
+                size: size as i32,
+                card: 0i32,
+                isSet: 1i32,
+                adjust: 0i32,
+                init: 0i32,
+                base: base as *mut libc::c_void,
+                data: base.wrapping_add(CTRLSZ * stride) as *mut libc::c_void,
+            },
+            buffer,
+            _element: PhantomData,
+        }
+    }
+
+    /**
+    Number of storage elements used by one element of the cell.
+    */
+    fn stride(&self) -> usize {
+        (self.cell.length as usize).max(1)
+    }
+
+    /**
+    Storage of the element at index, which must be lower than the capacity.
+    */
+    fn slot(&self, index: usize) -> &[T::Raw] {
+        let stride = self.stride();
+        let start = (CTRLSZ + index) * stride;
+        &self.buffer[start..start + stride]
+    }
+
+    /**
+    Number of elements in the cell, its cardinality.
+    */
+    pub fn len(&self) -> usize {
+        self.cell.card as usize
+    }
+
+    /**
+    Whether the cell has no elements.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /**
+    Maximum number of elements the cell can hold, its size.
+    */
+    pub fn capacity(&self) -> usize {
+        self.cell.size as usize
+    }
+
+    /**
+    Grow the capacity of the cell so it can hold at least `additional` more elements.
+    */
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len() + additional;
+        if required <= self.capacity() {
+            return;
+        }
+        let mut grown = Self::declare(required.max(2 * self.capacity()), self.cell.length as usize);
+        let used = (CTRLSZ + self.len()) * self.stride();
+        let start = CTRLSZ * self.stride();
+        grown.buffer[start..used].copy_from_slice(&self.buffer[start..used]);
+        grown.cell.card = self.cell.card;
+        grown.cell.isSet = self.cell.isSet;
+        *self = grown;
+    }
+
+    /**
+    Whether the elements of the cell are sorted and unique.
+    */
+    pub fn is_set(&self) -> bool {
+        self.cell.isSet != 0
+    }
+
+    /**
+    Element at index, or [`None`] if out of bounds.
+    */
+    pub fn get(&self, index: usize) -> Option<&T::Item> {
+        if index < self.len() {
+            Some(T::read(self.slot(index)))
+        } else {
+            None
+        }
+    }
+
+    /**
+    Iterate over the elements of the cell.
+    */
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            cell: self,
+            index: 0,
+        }
+    }
+
+    /**
+    Whether an element is in the cell.
+    */
+    pub fn contains(&self, item: &T::Item) -> bool
+    where
+        T::Item: PartialEq,
+    {
+        self.iter().any(|element| element == item)
+    }
+
+    /**
+    Append an element at the end of the cell, which is no longer a set. The cell grows if it is
+    full.
+
+    See [appndi_c][appndi_c link], [appndd_c][appndd_c link] and [appndc_c][appndc_c link].
+
+    [appndi_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/appndi_c.html
+    [appndd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/appndd_c.html
+    [appndc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/appndc_c.html
+    */
+    #[with_lock]
+    pub fn push<I: Into<T>>(&mut self, item: I) {
+        self.reserve(1);
+        item.into().append(&mut self.cell);
+        self.cell.isSet = 0i32;
+    }
+
+    /**
+    Insert an element into the set, keeping it sorted and unique. The cell grows if it is full.
+
+    See [insrti_c][insrti_c link], [insrtd_c][insrtd_c link] and [insrtc_c][insrtc_c link].
+
+    [insrti_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/insrti_c.html
+    [insrtd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/insrtd_c.html
+    [insrtc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/insrtc_c.html
+    */
+    #[with_lock]
+    pub fn insert<I: Into<T>>(&mut self, item: I) {
+        self.reserve(1);
+        item.into().insert(&mut self.cell);
+    }
+
+    /**
+    Sort the elements of the cell and remove duplicates, turning it into a set.
+
+    See [valid_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/valid_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn validate(&mut self) {
+        unsafe { c::valid_c(self.cell.size, self.cell.card, &mut self.cell) };
+        self.cell.isSet = 1i32;
+    }
+
+    /**
+    Union of two sets.
+
+    See [union_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/union_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn union(&self, other: &Self) -> Self {
+        self.operate(other, self.len() + other.len(), c::union_c)
+    }
+
+    /**
+    Intersection of two sets.
+
+    See [inter_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/inter_c.html).
+    */
+    #[with_lock]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.operate(other, self.len().min(other.len()), c::inter_c)
+    }
+
+    /**
+    Difference of two sets: the elements of `self` not in `other`.
+
+    See [diff_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/diff_c.html).
+    */
+    #[with_lock]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.operate(other, self.len(), c::diff_c)
+    }
+
+    /**
+    Apply a set operation on sets built from copies of both cells, into a new cell of given size.
+    */
+    fn operate(
+        &self,
+        other: &Self,
+        size: usize,
+        operation: unsafe extern "C" fn(*mut c::SpiceCell, *mut c::SpiceCell, *mut c::SpiceCell),
+    ) -> Self {
+        let mut a = self.to_set();
+        let mut b = other.to_set();
+        let mut output = Self::declare(size, self.cell.length.max(other.cell.length) as usize);
+        unsafe { operation(a.as_mut_ptr(), b.as_mut_ptr(), output.as_mut_ptr()) };
+        output
+    }
+
+    /**
+    Copy of the cell validated as a set.
+    */
+    fn to_set(&self) -> Self {
+        let mut set = self.clone();
+        if !set.is_set() {
+            set.validate_unlocked();
+        }
+        set
+    }
+
+    /**
+    Pointer to the underlying CSPICE cell.
+    */
+    pub fn as_ptr(&self) -> *const c::SpiceCell {
+        &self.cell
+    }
+
+    /**
+    Mutable pointer to the underlying CSPICE cell, to be sent to CSPICE.
+    */
+    pub fn as_mut_ptr(&mut self) -> *mut c::SpiceCell {
+        &mut self.cell
+    }
+}
+
+/**
+Cell or window filled by CSPICE, which can be declared again with a larger capacity.
+*/
+pub(crate) trait Output {
+    /**
+    Whether CSPICE cannot add any element to the output.
+    */
+    fn is_full(&self) -> bool;
+
+    /**
+    Declare the output again, empty and with twice its capacity.
+    */
+    fn grow(&mut self);
+}
+
+impl<T: CellElement> Output for Cell<T> {
+    fn is_full(&self) -> bool {
+        self.len() >= self.capacity()
+    }
+
+    fn grow(&mut self) {
+        *self = Self::declare(2 * self.capacity().max(1), self.cell.length as usize);
+    }
+}
+
+/**
+Call CSPICE to fill an output cell or window, growing the output and calling CSPICE again for as
+long as it is full and CSPICE signals it is too small.
+
+Any other error, such as a workspace of CSPICE being too small, is signaled again once the error
+action is restored, so it is handled as if the call was made only once.
+*/
+pub(crate) fn fill<O: Output, F: FnMut(&mut O)>(output: &mut O, mut call: F) {
+    loop {
+        match try_call(|| call(output)) {
+            Ok(()) => return,
+            Err(e) if output.is_full() && EXCESS_ERRORS.contains(&e.short.as_str()) => {
+                output.grow()
+            }
+            Err(e) => return error::signal(&e),
+        }
+    }
+}
+
+impl Cell<i32> {
+    /**
+    Declare a cell from integer.
+    */
+    #[deprecated(note = "use `Cell::<i32>::new` instead")]
+    pub fn new_int() -> Self {
+        Self::new(CELL_MAXID)
+    }
+
+    /**
+    Declare data from a cell at index.
+    */
+    #[deprecated(note = "index the cell instead")]
+    pub fn get_data_int(&self, index: usize) -> i32 {
+        self[index]
+    }
+}
+
+// The cell only points into its own buffer, which moves with it, so it can be sent to another
+// thread along with its elements
+unsafe impl<T: CellElement + Send> Send for Cell<T> {}
+
+impl<T: CellElement> Clone for Cell<T> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::declare(self.capacity(), self.cell.length as usize);
+        clone.buffer.copy_from_slice(&self.buffer);
+        clone.cell.card = self.cell.card;
+        clone.cell.isSet = self.cell.isSet;
+        clone.cell.adjust = self.cell.adjust;
+        clone.cell.init = self.cell.init;
+        clone
+    }
+}
+
+impl<T: CellElement> fmt::Debug for Cell<T>
+where
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: CellElement> Index<usize> for Cell<T> {
+    type Output = T::Item;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "lock"))]
+impl<T: CellElement> From<Vec<T>> for Cell<T> {
+    fn from(items: Vec<T>) -> Self {
+        let mut cell = Self::declare(items.len(), T::length(&items));
+        for item in items {
+            cell.push(item);
+        }
+        cell
+    }
+}
+
+impl<T: CellElement> From<Cell<T>> for Vec<T> {
+    fn from(cell: Cell<T>) -> Self {
+        cell.iter().map(ToOwned::to_owned).collect()
+    }
+}
+
+#[cfg(not(feature = "lock"))]
+impl<T: CellElement> FromIterator<T> for Cell<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<'a, T: CellElement> IntoIterator for &'a Cell<T> {
+    type Item = &'a T::Item;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/**
+Iterator over the elements of a [`Cell`].
+*/
+pub struct Iter<'a, T: CellElement> {
+    cell: &'a Cell<T>,
+    index: usize,
+}
+
+impl<'a, T: CellElement> Iterator for Iter<'a, T> {
+    type Item = &'a T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.cell.get(self.index)?;
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cell.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: CellElement> ExactSizeIterator for Iter<'a, T> {}
@@ -0,0 +1,201 @@
+/*!
+Conversions between coordinate systems, for positions and states.
+
+## Description
+
+CSPICE converts positions between rectangular coordinates and each of the other coordinate
+systems, and provides the Jacobian matrices of these conversions, which transform velocities. A
+[`Coordinates`] names a coordinate system along with its parameters, such as the radius and
+flattening of the reference spheroid for geodetic coordinates, so that tools can switch between
+systems generically.
+
+The coordinates are given in the order used by CSPICE:
+
+System | Coordinates
+-- | --
+[`Rectangular`][Coordinates::Rectangular] | `x`, `y`, `z`
+[`Latitudinal`][Coordinates::Latitudinal] | radius, longitude, latitude
+[`RaDec`][Coordinates::RaDec] | range, right ascension, declination
+[`Spherical`][Coordinates::Spherical] | radius, colatitude, longitude
+[`Cylindrical`][Coordinates::Cylindrical] | radius, longitude, `z`
+[`Geodetic`][Coordinates::Geodetic] | longitude, latitude, altitude
+[`Planetographic`][Coordinates::Planetographic] | longitude, latitude, altitude
+[`AzEl`][Coordinates::AzEl] | range, azimuth, elevation
+
+Angles are in radians. The conversions return a [`Result`] carrying the error signaled by CSPICE,
+for instance `SPICE(POINTONZAXIS)` when the Jacobian of a point on the Z axis is requested.
+
+With the `lock` feature, the conversions take the [`SpiceLock`][crate::SpiceLock] as an additional
+argument.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/frames.html).
+*/
+
+use crate::core::error::{try_call, Result};
+use crate::raw;
+use spice_derive::with_lock;
+
+/**
+A coordinate system and its parameters.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum Coordinates {
+    /// Rectangular coordinates.
+    Rectangular,
+    /// Latitudinal coordinates.
+    Latitudinal,
+    /// Range, right ascension and declination, with the right ascension in `[0, 2π)`.
+    RaDec,
+    /// Spherical coordinates.
+    Spherical,
+    /// Cylindrical coordinates.
+    Cylindrical,
+    /// Geodetic coordinates relative to a spheroid.
+    Geodetic {
+        /// Equatorial radius of the spheroid.
+        re: f64,
+        /// Flattening coefficient of the spheroid.
+        f: f64,
+    },
+    /// Planetographic coordinates relative to a spheroid, with the longitude sense of a body.
+    Planetographic {
+        /// Name of the body, which sets the positive sense of the longitude.
+        body: String,
+        /// Equatorial radius of the spheroid.
+        re: f64,
+        /// Flattening coefficient of the spheroid.
+        f: f64,
+    },
+    /// Range, azimuth and elevation.
+    AzEl {
+        /// Whether the azimuth increases counterclockwise about the Z axis.
+        azccw: bool,
+        /// Whether the elevation increases toward the +Z axis.
+        elplsz: bool,
+    },
+}
+
+impl Coordinates {
+    /**
+    Convert coordinates of this system to rectangular coordinates.
+    */
+    #[with_lock(unlocked)]
+    pub fn to_rectangular(&self, coords: [f64; 3]) -> Result<[f64; 3]> {
+        let [a, b, c] = coords;
+        try_call(|| match self {
+            Self::Rectangular => coords,
+            Self::Latitudinal => raw::latrec(a, b, c),
+            Self::RaDec => raw::radrec(a, b, c),
+            Self::Spherical => raw::sphrec(a, b, c),
+            Self::Cylindrical => raw::cylrec(a, b, c),
+            Self::Geodetic { re, f } => raw::georec(a, b, c, *re, *f),
+            Self::Planetographic { body, re, f } => raw::pgrrec(body, a, b, c, *re, *f),
+            Self::AzEl { azccw, elplsz } => raw::azlrec(a, b, c, *azccw, *elplsz),
+        })
+    }
+
+    /**
+    Convert rectangular coordinates to coordinates of this system.
+    */
+    #[with_lock(unlocked)]
+    pub fn from_rectangular(&self, rectan: [f64; 3]) -> Result<[f64; 3]> {
+        try_call(|| match self {
+            Self::Rectangular => rectan,
+            Self::Latitudinal => triple(raw::reclat(rectan)),
+            Self::RaDec => triple(raw::recrad(rectan)),
+            Self::Spherical => triple(raw::recsph(rectan)),
+            Self::Cylindrical => triple(raw::reccyl(rectan)),
+            Self::Geodetic { re, f } => triple(raw::recgeo(rectan, *re, *f)),
+            Self::Planetographic { body, re, f } => raw::recpgr(body, rectan, *re, *f),
+            Self::AzEl { azccw, elplsz } => triple(raw::recazl(rectan, *azccw, *elplsz)),
+        })
+    }
+
+    /**
+    Jacobian matrix of the conversion to rectangular coordinates, at the given coordinates of this
+    system.
+    */
+    #[with_lock(unlocked)]
+    pub fn jacobian_to_rectangular(&self, coords: [f64; 3]) -> Result<[[f64; 3]; 3]> {
+        let [a, b, c] = coords;
+        try_call(|| match self {
+            Self::Rectangular => IDENTITY,
+            Self::Latitudinal | Self::RaDec => raw::drdlat(a, b, c),
+            Self::Spherical => raw::drdsph(a, b, c),
+            Self::Cylindrical => raw::drdcyl(a, b, c),
+            Self::Geodetic { re, f } => raw::drdgeo(a, b, c, *re, *f),
+            Self::Planetographic { body, re, f } => raw::drdpgr(body, a, b, c, *re, *f),
+            Self::AzEl { azccw, elplsz } => raw::drdazl(a, b, c, *azccw, *elplsz),
+        })
+    }
+
+    /**
+    Jacobian matrix of the conversion from rectangular coordinates, at the given rectangular
+    coordinates.
+    */
+    #[with_lock(unlocked)]
+    pub fn jacobian_from_rectangular(&self, rectan: [f64; 3]) -> Result<[[f64; 3]; 3]> {
+        let [x, y, z] = rectan;
+        try_call(|| match self {
+            Self::Rectangular => IDENTITY,
+            Self::Latitudinal | Self::RaDec => raw::dlatdr(x, y, z),
+            Self::Spherical => raw::dsphdr(x, y, z),
+            Self::Cylindrical => raw::dcyldr(x, y, z),
+            Self::Geodetic { re, f } => raw::dgeodr(x, y, z, *re, *f),
+            Self::Planetographic { body, re, f } => raw::dpgrdr(body, x, y, z, *re, *f),
+            Self::AzEl { azccw, elplsz } => raw::dazldr(x, y, z, *azccw, *elplsz),
+        })
+    }
+
+    /**
+    Convert a state of this system, its coordinates followed by their rates, to a rectangular
+    state, position followed by velocity.
+    */
+    #[with_lock]
+    pub fn state_to_rectangular(&self, state: [f64; 6]) -> Result<[f64; 6]> {
+        let coords = [state[0], state[1], state[2]];
+        let rates = [state[3], state[4], state[5]];
+        let position = self.to_rectangular_unlocked(coords)?;
+        let velocity = raw::mxv(self.jacobian_to_rectangular_unlocked(coords)?, rates);
+        Ok(concat(position, velocity))
+    }
+
+    /**
+    Convert a rectangular state, position followed by velocity, to a state of this system, its
+    coordinates followed by their rates.
+    */
+    #[with_lock]
+    pub fn state_from_rectangular(&self, state: [f64; 6]) -> Result<[f64; 6]> {
+        let position = [state[0], state[1], state[2]];
+        let velocity = [state[3], state[4], state[5]];
+        let coords = self.from_rectangular_unlocked(position)?;
+        let rates = raw::mxv(self.jacobian_from_rectangular_unlocked(position)?, velocity);
+        Ok(concat(coords, rates))
+    }
+}
+
+/**
+Identity matrix, the Jacobian of rectangular coordinates.
+*/
+const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/**
+Coordinates returned as a tuple by CSPICE.
+*/
+fn triple((a, b, c): (f64, f64, f64)) -> [f64; 3] {
+    [a, b, c]
+}
+
+/**
+State made of a position and a velocity.
+*/
+fn concat(position: [f64; 3], velocity: [f64; 3]) -> [f64; 6] {
+    [
+        position[0],
+        position[1],
+        position[2],
+        velocity[0],
+        velocity[1],
+        velocity[2],
+    ]
+}
@@ -0,0 +1,129 @@
+/*!
+Errors signaled by the SPICE error subsystem, surfaced as Rust [`Result`]s.
+
+## Description
+
+By default, CSPICE aborts the program as soon as an error is signaled. The functions of the
+[`fallible`][crate::fallible] module instead switch the error action to `RETURN` for the duration
+of the call, check [`failed_c`][crate::c::failed_c] afterwards and, if an error was signaled,
+collect its messages and traceback into a [`SpiceError`] before resetting the error status.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/error.html).
+*/
+
+use crate::{c, cstr, fcstr, mallocstr, mptr};
+use thiserror::Error;
+
+/**
+Maximum size of the error action string.
+*/
+const ACTION_LEN: usize = 32;
+
+/**
+Maximum size of the traceback string.
+*/
+const TRACEBACK_LEN: usize = 1024;
+
+/**
+Result of a function that can signal a SPICE error.
+*/
+pub type Result<T> = std::result::Result<T, SpiceError>;
+
+/**
+An error signaled by CSPICE.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{short}: {long}")]
+pub struct SpiceError {
+    /// Short error message, such as `SPICE(NOLEAPSECONDS)`.
+    pub short: String,
+    /// Long error message, explaining the cause of the error.
+    pub long: String,
+    /// Traceback of the CSPICE routines active when the error was signaled.
+    pub traceback: String,
+}
+
+/**
+Run a function calling CSPICE with the error action set to `RETURN`, and convert any signaled error
+into a [`SpiceError`].
+
+The previous error action is restored afterwards, even if the function panics, and the error
+status is reset so the next call starts clean.
+*/
+pub fn try_call<T, F>(f: F) -> Result<T>
+where
+    F: FnOnce() -> T,
+{
+    let _previous = ErrorAction(erract_get());
+    erract_set("RETURN");
+    let output = f();
+    match take_error() {
+        Some(error) => Err(error),
+        None => Ok(output),
+    }
+}
+
+/**
+Error action restored when dropped.
+*/
+struct ErrorAction(String);
+
+impl Drop for ErrorAction {
+    fn drop(&mut self) {
+        erract_set(&self.0);
+    }
+}
+
+/**
+Collect the error currently signaled, if any, and reset the error status.
+*/
+pub fn take_error() -> Option<SpiceError> {
+    if unsafe { c::failed_c() } == 0 {
+        return None;
+    }
+    let error = SpiceError {
+        short: getmsg("SHORT", c::SPICE_ERROR_SMSGLN as usize),
+        long: getmsg("LONG", c::SPICE_ERROR_LMSGLN as usize),
+        traceback: qcktrc(),
+    };
+    unsafe { c::reset_c() };
+    Some(error)
+}
+
+/**
+Signal an error again under the current error action, as if CSPICE had signaled it.
+*/
+pub(crate) fn signal(error: &SpiceError) {
+    let mut long = cstr!(error.long.as_str());
+    let mut short = cstr!(error.short.as_str());
+    unsafe {
+        c::setmsg_c(mptr!(long));
+        c::sigerr_c(mptr!(short));
+    }
+}
+
+fn qcktrc() -> String {
+    let mut trace = mallocstr!(TRACEBACK_LEN);
+    unsafe { c::qcktrc_c(TRACEBACK_LEN as _, mptr!(trace)) };
+    fcstr!(trace).trim_end().to_string()
+}
+
+fn getmsg(option: &str, lenout: usize) -> String {
+    let mut option = cstr!(option);
+    let mut msg = mallocstr!(lenout);
+    unsafe { c::getmsg_c(mptr!(option), lenout as _, mptr!(msg)) };
+    fcstr!(msg).trim_end().to_string()
+}
+
+fn erract_get() -> String {
+    let mut operation = cstr!("GET");
+    let mut action = mallocstr!(ACTION_LEN);
+    unsafe { c::erract_c(mptr!(operation), ACTION_LEN as _, mptr!(action)) };
+    fcstr!(action)
+}
+
+fn erract_set(action: &str) {
+    let mut operation = cstr!("SET");
+    let mut action = cstr!(action);
+    unsafe { c::erract_c(mptr!(operation), 0, mptr!(action)) };
+}
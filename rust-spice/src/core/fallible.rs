@@ -0,0 +1,557 @@
+/*!
+Functions returning a [`Result`] instead of letting CSPICE abort on error.
+
+## Description
+
+Each function of this module either calls its counterpart from [`raw`] or [`neat`] through
+[`try_call`], or is the `try_<name>` version generated by
+[`cspice_proc!`][spice_derive::cspice_proc!] along with a binding of [`raw`] declared with the
+attribute [`fallible`][macro@spice_derive::fallible], re-exported here under the name of the
+binding. Any error signaled by CSPICE (unknown body, missing kernel, unparsable time string, ...)
+is returned as a [`SpiceError`][crate::SpiceError] instead of aborting the process.
+
+Pure vector and matrix functions such as [`raw::vdot`] or [`raw::mxv`] do not signal errors and
+have no counterpart here.
+*/
+
+use crate::core::error::{try_call, Result};
+use crate::core::raw::{Cell, DLADSC};
+use crate::core::time::Et;
+use crate::core::window::Window;
+use crate::{neat, raw};
+use na::{Rotation3, Vector3};
+#[cfg(any(feature = "lock", doc))]
+use {crate::SpiceLock, spice_derive::impl_for};
+
+pub use crate::core::raw::{
+    try_bodfnd as bodfnd, try_bodn2c as bodn2c, try_ckcov as ckcov, try_ckobj as ckobj,
+    try_cvpool as cvpool, try_dascls as dascls, try_dasopr as dasopr, try_dazldr as dazldr,
+    try_dcyldr as dcyldr, try_dgeodr as dgeodr, try_dlabfs as dlabfs, try_dlatdr as dlatdr,
+    try_dpgrdr as dpgrdr, try_drdgeo as drdgeo, try_drdpgr as drdpgr, try_dskgd as dskgd,
+    try_dskn02 as dskn02, try_dskobj as dskobj, try_dskx02 as dskx02, try_dskz02 as dskz02,
+    try_dsphdr as dsphdr, try_eul2m as eul2m, try_frinfo as frinfo, try_furnsh as furnsh,
+    try_georec as georec, try_gfdist as gfdist, try_gfilum as gfilum, try_gfoclt as gfoclt,
+    try_gfpa as gfpa, try_gfposc as gfposc, try_gfrfov as gfrfov, try_gfrr as gfrr,
+    try_gfsep as gfsep, try_gfsntc as gfsntc, try_gfsubc as gfsubc, try_gftfov as gftfov,
+    try_illumf as illumf, try_kclear as kclear, try_ktotal as ktotal, try_m2eul as m2eul,
+    try_m2q as m2q, try_namfrm as namfrm, try_occult as occult, try_pckcov as pckcov,
+    try_pgrrec as pgrrec, try_pxform as pxform, try_pxfrm2 as pxfrm2, try_raxisa as raxisa,
+    try_recgeo as recgeo, try_sce2c as sce2c, try_sce2t as sce2t, try_scencd as scencd,
+    try_scs2e as scs2e, try_sct2e as sct2e, try_sincpt as sincpt, try_spkcls as spkcls,
+    try_spkcov as spkcov, try_spkezr as spkezr, try_spkobj as spkobj, try_spkopn as spkopn,
+    try_spkpos as spkpos, try_spkw09 as spkw09, try_str2et as str2et, try_surfpt as surfpt,
+    try_sxform as sxform, try_twovec as twovec, try_unload as unload, try_utc2et as utc2et,
+};
+
+/**
+Translate the SPICE integer code of a body into a common name for that body.
+
+See [`neat::bodc2n`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_bodc2n))]
+pub fn bodc2n(code: i32) -> Result<(String, bool)> {
+    try_call(|| neat::bodc2n(code))
+}
+
+/**
+Fetch from the kernel pool the double precision values of an item associated with a body.
+
+See [`raw::bodvrd`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_bodvrd))]
+pub fn bodvrd(bodynm: &str, item: &str, maxn: usize) -> Result<Vec<f64>> {
+    try_call(|| raw::bodvrd(bodynm, item, maxn))
+}
+
+/**
+Return the frame name, frame ID, and center associated with a given frame class and class ID.
+
+See [`neat::ccifrm`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_ccifrm))]
+pub fn ccifrm(frclss: i32, clssid: i32) -> Result<(i32, String, i32, bool)> {
+    try_call(|| neat::ccifrm(frclss, clssid))
+}
+
+/**
+Retrieve frame ID code and name to associate with a frame center.
+
+See [`neat::cidfrm`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_cidfrm))]
+pub fn cidfrm(cent: i32) -> Result<(i32, String, bool)> {
+    try_call(|| neat::cidfrm(cent))
+}
+
+/**
+Find the coverage window for a specified object over all the loaded CK files.
+
+See [`neat::ckcov_loaded`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_ckcov_loaded))]
+pub fn ckcov_loaded(
+    idcode: i32,
+    needav: bool,
+    level: &str,
+    tol: f64,
+    timsys: &str,
+) -> Result<Window> {
+    try_call(|| neat::ckcov_loaded(idcode, needav, level, tol, timsys))
+}
+
+/**
+Get pointing (attitude) for a specified spacecraft clock time.
+
+See [`neat::ckgp`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_ckgp))]
+pub fn ckgp(inst: i32, sclkdp: f64, tol: f64, ref_: &str) -> Result<Option<(Rotation3<f64>, f64)>> {
+    try_call(|| neat::ckgp(inst, sclkdp, tol, ref_))
+}
+
+/**
+Get pointing (attitude) and angular velocity for a specified spacecraft clock time.
+
+See [`neat::ckgpav`].
+*/
+#[allow(clippy::type_complexity)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_ckgpav))]
+pub fn ckgpav(
+    inst: i32,
+    sclkdp: f64,
+    tol: f64,
+    ref_: &str,
+) -> Result<Option<(Rotation3<f64>, Vector3<f64>, f64)>> {
+    try_call(|| neat::ckgpav(inst, sclkdp, tol, ref_))
+}
+
+/**
+Find the set of ID codes of all objects in the loaded CK files.
+
+See [`neat::ckobj_loaded`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_ckobj_loaded))]
+pub fn ckobj_loaded() -> Result<Cell<i32>> {
+    try_call(neat::ckobj_loaded)
+}
+
+/**
+Retrieve frame ID code and name to associate with an object.
+
+See [`neat::cnmfrm`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_cnmfrm))]
+pub fn cnmfrm(cname: &str) -> Result<(i32, String, bool)> {
+    try_call(|| neat::cnmfrm(cname))
+}
+
+/**
+Return the value of Delta ET (ET-UTC) for an input epoch.
+
+See [`raw::deltet`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_deltet))]
+pub fn deltet(epoch: f64, eptype: &str) -> Result<f64> {
+    try_call(|| raw::deltet(epoch, eptype))
+}
+
+/**
+Fetch triangular plates from a type 2 DSK segment.
+
+See [`neat::dskp02`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_dskp02))]
+pub fn dskp02(handle: i32, dladsc: DLADSC) -> Result<Vec<[i32; 3]>> {
+    try_call(|| neat::dskp02(handle, dladsc))
+}
+
+/**
+Fetch vertices from a type 2 DSK segment.
+
+See [`neat::dskv02`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_dskv02))]
+pub fn dskv02(handle: i32, dladsc: DLADSC) -> Result<Vec<[f64; 3]>> {
+    try_call(|| neat::dskv02(handle, dladsc))
+}
+
+/**
+Return the number of components and the type of a kernel pool variable.
+
+See [`raw::dtpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_dtpool))]
+pub fn dtpool(name: &str) -> Result<(i32, char, bool)> {
+    try_call(|| raw::dtpool(name))
+}
+
+/**
+Given an ephemeris epoch, compute the local solar time for an object on the surface of a body at a
+specified longitude, in radians.
+
+See [`neat::et2lst`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_et2lst))]
+pub fn et2lst(
+    et: impl Into<Et>,
+    body: i32,
+    lon: f64,
+    lon_type: &str,
+) -> Result<(i32, i32, i32, String, String)> {
+    try_call(|| neat::et2lst(et, body, lon, lon_type))
+}
+
+/**
+Convert an input time from ephemeris seconds past J2000 to Calendar, Day-of-Year, or Julian Date
+format, UTC.
+
+See [`neat::et2utc`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_et2utc))]
+pub fn et2utc(et: impl Into<Et>, format: &str, prec: i32) -> Result<String> {
+    try_call(|| neat::et2utc(et, format, prec))
+}
+
+/**
+Convert from an ephemeris epoch measured in seconds past the epoch of J2000 to a calendar string
+format using a formal calendar free of leapseconds.
+
+See [`neat::etcal`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_etcal))]
+pub fn etcal(et: impl Into<Et>) -> Result<String> {
+    try_call(|| neat::etcal(et))
+}
+
+/**
+Retrieve the name of a reference frame associated with a SPICE ID code, or an empty string if
+there is none.
+
+See [`neat::frmnam`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_frmnam))]
+pub fn frmnam(frcode: i32) -> Result<String> {
+    try_call(|| neat::frmnam(frcode))
+}
+
+/**
+Return the character value of a kernel variable from the kernel pool.
+
+See [`raw::gcpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_gcpool))]
+pub fn gcpool(name: &str, start: usize, room: usize, lenout: usize) -> Result<(Vec<String>, bool)> {
+    try_call(|| raw::gcpool(name, start, room, lenout))
+}
+
+/**
+Return the d.p. value of a kernel variable from the kernel pool.
+
+See [`raw::gdpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_gdpool))]
+pub fn gdpool(name: &str, start: usize, room: usize) -> Result<(Vec<f64>, bool)> {
+    try_call(|| raw::gdpool(name, start, room))
+}
+
+/**
+Return the field-of-view (FOV) parameters for a specified instrument.
+
+See [`raw::getfov`].
+*/
+#[allow(clippy::type_complexity)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_getfov))]
+pub fn getfov(
+    instid: isize,
+    room: usize,
+    shapelen: usize,
+    framelen: usize,
+) -> Result<(String, String, [f64; 3], Vec<[f64; 3]>)> {
+    try_call(|| raw::getfov(instid, room, shapelen, framelen))
+}
+
+/**
+Perform a GF search on a user defined boolean quantity, returning the window where it is true.
+
+See [`raw::gfudb`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_gfudb))]
+pub fn gfudb<B: Fn(f64) -> bool>(udfunb: B, step: f64, cnfine: &Window) -> Result<Window> {
+    try_call(|| raw::gfudb(udfunb, step, cnfine))
+}
+
+/**
+Perform a GF search on a user defined scalar quantity, returning the window where it satisfies
+a constraint.
+
+See [`raw::gfuds`].
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_gfuds))]
+pub fn gfuds<S: Fn(f64) -> f64, D: Fn(f64) -> bool>(
+    udfuns: S,
+    decreasing: D,
+    relate: &str,
+    refval: f64,
+    adjust: f64,
+    step: f64,
+    nintvls: i32,
+    cnfine: &Window,
+) -> Result<Window> {
+    try_call(|| {
+        raw::gfuds(
+            udfuns, decreasing, relate, refval, adjust, step, nintvls, cnfine,
+        )
+    })
+}
+
+/**
+Return the integer value of a kernel variable from the kernel pool.
+
+See [`raw::gipool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_gipool))]
+pub fn gipool(name: &str, start: usize, room: usize) -> Result<(Vec<i32>, bool)> {
+    try_call(|| raw::gipool(name, start, room))
+}
+
+/**
+Return names of kernel variables matching a specified template.
+
+See [`raw::gnpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_gnpool))]
+pub fn gnpool(name: &str, start: usize, room: usize, lenout: usize) -> Result<(Vec<String>, bool)> {
+    try_call(|| raw::gnpool(name, start, room, lenout))
+}
+
+/**
+Return data for the nth kernel that is among a list of specified kernel types.
+
+See [`neat::kdata`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_kdata))]
+pub fn kdata(which: i32, kind: &str) -> Result<(String, String, String, i32, bool)> {
+    try_call(|| neat::kdata(which, kind))
+}
+
+/**
+Return information about a loaded SPICE kernel specified by name.
+
+See [`neat::kinfo`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_kinfo))]
+pub fn kinfo(file: &str) -> Result<(String, String, i32, bool)> {
+    try_call(|| neat::kinfo(file))
+}
+
+/**
+Find the first epoch, not earlier than `after`, at which the local solar time reaches `time`.
+
+See [`neat::lst2et`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_lst2et))]
+pub fn lst2et(
+    body: i32,
+    lon: f64,
+    lon_type: &str,
+    time: (i32, i32, i32),
+    after: impl Into<Et>,
+    step: f64,
+) -> Result<Et> {
+    try_call(|| neat::lst2et(body, lon, lon_type, time, after, step)).and_then(|et| et)
+}
+
+/**
+Find the coverage window for a specified reference frame class ID over all the loaded binary PCK
+files.
+
+See [`neat::pckcov_loaded`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_pckcov_loaded))]
+pub fn pckcov_loaded(idcode: i32) -> Result<Window> {
+    try_call(|| neat::pckcov_loaded(idcode))
+}
+
+/**
+Insert character data into the kernel pool.
+
+See [`raw::pcpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_pcpool))]
+pub fn pcpool(name: &str, cvals: &[&str]) -> Result<()> {
+    try_call(|| raw::pcpool(name, cvals))
+}
+
+/**
+Insert double precision data into the kernel pool.
+
+See [`raw::pdpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_pdpool))]
+pub fn pdpool(name: &str, dvals: &[f64]) -> Result<()> {
+    try_call(|| raw::pdpool(name, dvals))
+}
+
+/**
+Insert integer data into the kernel pool.
+
+See [`raw::pipool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_pipool))]
+pub fn pipool(name: &str, ivals: &[i32]) -> Result<()> {
+    try_call(|| raw::pipool(name, ivals))
+}
+
+/**
+Convert rectangular coordinates to planetographic coordinates.
+
+See [`raw::recpgr`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_recpgr))]
+pub fn recpgr(body: &str, rectan: [f64; 3], re: f64, f: f64) -> Result<[f64; 3]> {
+    try_call(|| raw::recpgr(body, rectan, re, f))
+}
+
+/**
+Convert double precision encoding of spacecraft clock time into a character representation.
+
+See [`neat::scdecd`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_scdecd))]
+pub fn scdecd(sc: i32, sclkdp: f64) -> Result<String> {
+    try_call(|| neat::scdecd(sc, sclkdp))
+}
+
+/**
+Convert an epoch specified as ephemeris seconds past J2000 (ET) to a character string
+representation of a spacecraft clock value (SCLK).
+
+See [`neat::sce2s`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_sce2s))]
+pub fn sce2s(sc: i32, et: impl Into<Et>) -> Result<String> {
+    try_call(|| neat::sce2s(sc, et))
+}
+
+/**
+Get spacecraft clock partition information from a spacecraft clock kernel file.
+
+See [`raw::scpart`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_scpart))]
+pub fn scpart(sc: i32) -> Result<Vec<(f64, f64)>> {
+    try_call(|| raw::scpart(sc))
+}
+
+/**
+Find the coverage window for a specified ephemeris object over all the loaded SPK files.
+
+See [`neat::spkcov_loaded`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_spkcov_loaded))]
+pub fn spkcov_loaded(idcode: i32) -> Result<Window> {
+    try_call(|| neat::spkcov_loaded(idcode))
+}
+
+/**
+Find the set of ID codes of all objects in the loaded SPK files.
+
+See [`neat::spkobj_loaded`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_spkobj_loaded))]
+pub fn spkobj_loaded() -> Result<Cell<i32>> {
+    try_call(neat::spkobj_loaded)
+}
+
+/**
+Compute the rectangular coordinates of the sub-observer point on a target body at a specified
+epoch.
+
+See [`raw::subpnt`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_subpnt))]
+pub fn subpnt(
+    method: &str,
+    target: &str,
+    et: impl Into<Et>,
+    fixref: &str,
+    abcorr: &str,
+    obsrvr: &str,
+) -> Result<([f64; 3], f64, [f64; 3])> {
+    try_call(|| raw::subpnt(method, target, et, fixref, abcorr, obsrvr))
+}
+
+/**
+Add a name to the list of agents to notify whenever a member of a list of kernel variables is
+updated.
+
+See [`raw::swpool`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_swpool))]
+pub fn swpool(agent: &str, names: &[&str]) -> Result<()> {
+    try_call(|| raw::swpool(agent, names))
+}
+
+/**
+Transform a state from one frame to another at a specified epoch.
+
+See [`neat::sxform_state`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_sxform_state))]
+pub fn sxform_state(state: [f64; 6], from: &str, to: &str, et: impl Into<Et>) -> Result<[f64; 6]> {
+    try_call(|| neat::sxform_state(state, from, to, et))
+}
+
+/**
+Set and retrieve the defaults associated with calendar input strings.
+
+See [`neat::timdef`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_timdef))]
+pub fn timdef(action: &str, item: &str, value: &str) -> Result<String> {
+    try_call(|| neat::timdef(action, item, value))
+}
+
+/**
+Convert an input epoch represented in TDB seconds past the TDB epoch of J2000 to a character
+string formatted to the specifications of a user's format picture.
+
+See [`neat::timout`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_timout))]
+pub fn timout(et: impl Into<Et>, pictur: &str) -> Result<String> {
+    try_call(|| neat::timout(et, pictur))
+}
+
+/**
+Parse a time string and return seconds past the J2000 epoch on a formal calendar, or the
+diagnostic message explaining why the string could not be parsed.
+
+See [`neat::tparse`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_tparse))]
+pub fn tparse(string: &str) -> Result<std::result::Result<f64, String>> {
+    try_call(|| neat::tparse(string))
+}
+
+/**
+Create a time format picture suitable for use by the routine [`timout`] from a given sample time
+string, or the diagnostic message explaining why the sample could not be understood.
+
+See [`neat::tpictr`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_tpictr))]
+pub fn tpictr(sample: &str) -> Result<std::result::Result<String, String>> {
+    try_call(|| neat::tpictr(sample))
+}
+
+/**
+Transform time from one uniform scale to another.
+
+See [`raw::unitim`].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = try_unitim))]
+pub fn unitim(epoch: f64, insys: &str, outsys: &str) -> Result<f64> {
+    try_call(|| raw::unitim(epoch, insys, outsys))
+}
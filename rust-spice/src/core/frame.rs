@@ -0,0 +1,227 @@
+/*!
+Metadata of the reference frames known to the frame subsystem.
+
+## Description
+
+A reference frame is known by its name, such as `"J2000"` or `"IAU_EARTH"`, and by its frame ID
+code. It is defined by its class, telling how the frame is realized (inertial, from a binary PCK,
+from a CK, ...), by its class ID, identifying the frame within its class, and by its center, the
+NAIF ID of the body at which the frame is centered.
+
+A [`Frame`] caches this metadata, so a frame can be looked up once, for instance the body-fixed
+frame of a body with [`Frame::body_fixed`], then given by name to the geometry functions. The
+lookups return [`None`] when the frame is not known, either built in or defined by a frame kernel
+loaded.
+
+With the `lock` feature, the lookups and [`Frame::rotation_to`] take the
+[`SpiceLock`][crate::SpiceLock] as an additional argument.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/frames.html).
+*/
+
+use crate::core::error::Result;
+use crate::core::linalg::to_rotation;
+use crate::{fallible, neat, raw, Et};
+use na::Rotation3;
+use spice_derive::with_lock;
+use std::fmt;
+
+/**
+Class of a reference frame, telling how the frame is realized.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameClass {
+    /// Inertial frame, built in.
+    Inertial,
+    /// Body-fixed frame whose orientation is given by a PCK.
+    Pck,
+    /// Frame whose orientation is given by a CK.
+    Ck,
+    /// Frame at a fixed offset from another frame, given by a text kernel.
+    Fixed,
+    /// Frame whose orientation is computed from the state of bodies.
+    Dynamic,
+    /// Frame switching between base frames according to their priority and availability.
+    Switch,
+}
+
+impl FrameClass {
+    /**
+    Class of the given SPICE code, or [`None`] if the code is unknown.
+    */
+    pub fn from_code(code: i32) -> Option<Self> {
+        match code {
+            1 => Some(Self::Inertial),
+            2 => Some(Self::Pck),
+            3 => Some(Self::Ck),
+            4 => Some(Self::Fixed),
+            5 => Some(Self::Dynamic),
+            6 => Some(Self::Switch),
+            _ => None,
+        }
+    }
+
+    /**
+    SPICE code of the class.
+    */
+    pub fn code(self) -> i32 {
+        match self {
+            Self::Inertial => 1,
+            Self::Pck => 2,
+            Self::Ck => 3,
+            Self::Fixed => 4,
+            Self::Dynamic => 5,
+            Self::Switch => 6,
+        }
+    }
+}
+
+/**
+A reference frame and its metadata.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Frame {
+    name: String,
+    id: i32,
+    class: FrameClass,
+    class_id: i32,
+    center: i32,
+}
+
+impl Frame {
+    /**
+    Frame of the given name, case insensitive.
+
+    See [namfrm_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/namfrm_c.html).
+    */
+    #[with_lock]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match raw::namfrm(name) {
+            0 => None,
+            id => Self::from_id_unlocked(id),
+        }
+    }
+
+    /**
+    Frame of the given frame ID code.
+
+    See [frinfo_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/frinfo_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn from_id(id: i32) -> Option<Self> {
+        let (center, class, class_id, found) = raw::frinfo(id);
+        if !found {
+            return None;
+        }
+        let name = neat::frmnam(id);
+        if name.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name,
+            id,
+            class: FrameClass::from_code(class)?,
+            class_id,
+            center,
+        })
+    }
+
+    /**
+    Frame of the given class and class ID.
+
+    See [ccifrm_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ccifrm_c.html).
+    */
+    #[with_lock]
+    pub fn from_class(class: FrameClass, class_id: i32) -> Option<Self> {
+        match neat::ccifrm(class.code(), class_id) {
+            (id, name, center, true) => Some(Self {
+                name,
+                id,
+                class,
+                class_id,
+                center,
+            }),
+            _ => None,
+        }
+    }
+
+    /**
+    Body-fixed frame associated with the body of the given name, such as `"IAU_EARTH"` for the
+    Earth, or the frame set by the `OBJECT_<name>_FRAME` variable of the kernel pool.
+
+    See [cnmfrm_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cnmfrm_c.html).
+    */
+    #[with_lock]
+    pub fn body_fixed(body: &str) -> Option<Self> {
+        match neat::cnmfrm(body) {
+            (id, _, true) => Self::from_id_unlocked(id),
+            _ => None,
+        }
+    }
+
+    /**
+    Body-fixed frame associated with the body of the given NAIF ID, or the frame set by the
+    `OBJECT_<id>_FRAME` variable of the kernel pool.
+
+    See [cidfrm_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cidfrm_c.html).
+    */
+    #[with_lock]
+    pub fn body_fixed_of(body: i32) -> Option<Self> {
+        match neat::cidfrm(body) {
+            (id, _, true) => Self::from_id_unlocked(id),
+            _ => None,
+        }
+    }
+
+    /**
+    Name of the frame, as given to the geometry functions.
+    */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /**
+    Frame ID code.
+    */
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /**
+    Class of the frame.
+    */
+    pub fn class(&self) -> FrameClass {
+        self.class
+    }
+
+    /**
+    ID of the frame within its class, such as the ID of the body of a PCK frame or the ID of the
+    structure of a CK frame.
+    */
+    pub fn class_id(&self) -> i32 {
+        self.class_id
+    }
+
+    /**
+    NAIF ID of the body at which the frame is centered.
+    */
+    pub fn center(&self) -> i32 {
+        self.center
+    }
+
+    /**
+    Rotation of position vectors from this frame to another at the given epoch.
+
+    See [pxform_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pxform_c.html).
+    */
+    #[with_lock]
+    pub fn rotation_to(&self, to: &Frame, et: impl Into<Et>) -> Result<Rotation3<f64>> {
+        fallible::pxform(&self.name, &to.name, et).map(to_rotation)
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
@@ -0,0 +1,365 @@
+/*!
+Kernels loaded for the lifetime of a Rust value.
+
+## Description
+
+[`raw::furnsh`] and [`raw::unload`] act on the global kernel pool, so a kernel loaded by a
+computation which panics stays loaded and is seen by all the following computations. Loading a
+kernel through [`Kernel::load`] instead returns a [`KernelGuard`] which unloads the kernel when it
+goes out of scope, whatever the way the scope is left.
+
+A metakernel is loaded and unloaded with all the kernels it lists, so a single guard is enough to
+hold it. A [`KernelSet`] holds several guards and unloads them in the reverse order of loading.
+
+The kernels currently loaded, whatever the way they were loaded, are listed by [`loaded_kernels`]
+as [`LoadedKernel`]s.
+
+With the `lock` feature, [`Kernel::load`], [`KernelSet::new`], [`KernelSet::load`],
+[`LoadedKernel::find`] and [`loaded_kernels`] take the [`SpiceLock`][crate::SpiceLock] as an
+additional argument. The guards and sets keep it borrowed until they are dropped, as they call
+CSPICE afterwards.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/kernel.html).
+*/
+
+use crate::core::error::Result;
+use crate::core::LockRef;
+use crate::{fallible, neat, raw};
+use spice_derive::with_lock;
+use std::fmt;
+use std::mem::{self, ManuallyDrop};
+use std::str::FromStr;
+use thiserror::Error;
+
+/**
+Entry point to load kernels for the lifetime of a guard.
+*/
+pub struct Kernel;
+
+impl Kernel {
+    /**
+    Load a kernel, or a metakernel with all the kernels it lists, until the returned guard is
+    dropped.
+
+    See [furnsh_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/furnsh_c.html).
+    */
+    #[with_lock(lock: &'l crate::SpiceLock)]
+    pub fn load<'l>(path: &str) -> Result<KernelGuard<'l>> {
+        KernelGuard::load(path, lock_ref!(lock))
+    }
+}
+
+/**
+A loaded kernel, unloaded when dropped.
+
+See [unload_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unload_c.html).
+*/
+#[derive(Debug)]
+#[must_use = "the kernel is unloaded as soon as the guard is dropped"]
+pub struct KernelGuard<'l> {
+    path: String,
+    _lock: LockRef<'l>,
+}
+
+impl<'l> KernelGuard<'l> {
+    fn load(path: &str, lock: LockRef<'l>) -> Result<Self> {
+        fallible::furnsh(path)?;
+        Ok(Self {
+            path: path.to_string(),
+            _lock: lock,
+        })
+    }
+
+    /**
+    Path of the kernel, as given to [`Kernel::load`].
+    */
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /**
+    Paths of the kernels loaded through this one, if it is a metakernel.
+    */
+    pub fn children(&self) -> Vec<String> {
+        loaded_kernels_unlocked(&KernelKind::ALL)
+            .filter(|kernel| kernel.source.as_deref() == Some(self.path.as_str()))
+            .map(|kernel| kernel.path)
+            .collect()
+    }
+
+    /**
+    Keep the kernel loaded past the lifetime of the guard, and return its path.
+    */
+    pub fn keep(self) -> String {
+        let mut guard = ManuallyDrop::new(self);
+        mem::take(&mut guard.path)
+    }
+}
+
+impl Drop for KernelGuard<'_> {
+    fn drop(&mut self) {
+        // Unloading a kernel which was already cleared, e.g. by kclear, is not worth a panic.
+        let _ = fallible::unload(&self.path);
+    }
+}
+
+/**
+A set of loaded kernels, unloaded in the reverse order of loading when dropped.
+*/
+#[derive(Debug)]
+#[must_use = "the kernels are unloaded as soon as the set is dropped"]
+pub struct KernelSet<'l> {
+    guards: Vec<KernelGuard<'l>>,
+    lock: LockRef<'l>,
+}
+
+impl<'l> KernelSet<'l> {
+    /**
+    An empty set.
+    */
+    #[with_lock(lock: &'l crate::SpiceLock)]
+    pub fn new() -> Self {
+        Self::empty(lock_ref!(lock))
+    }
+
+    fn empty(lock: LockRef<'l>) -> Self {
+        Self {
+            guards: Vec::new(),
+            lock,
+        }
+    }
+
+    /**
+    Load all the kernels of `paths`, in order. If one of them fails to load, the kernels already
+    loaded are unloaded before the error is returned.
+    */
+    #[with_lock(lock: &'l crate::SpiceLock)]
+    pub fn load<I, S>(paths: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut set = Self::empty(lock_ref!(lock));
+        for path in paths {
+            set.push(path.as_ref())?;
+        }
+        Ok(set)
+    }
+
+    /**
+    Load one more kernel into the set.
+    */
+    pub fn push(&mut self, path: &str) -> Result<()> {
+        self.guards.push(KernelGuard::load(path, self.lock)?);
+        Ok(())
+    }
+
+    /**
+    Number of kernels loaded directly by the set, not counting the ones listed by metakernels.
+    */
+    pub fn len(&self) -> usize {
+        self.guards.len()
+    }
+
+    /**
+    Whether the set holds no kernel.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.guards.is_empty()
+    }
+
+    /**
+    Iterate over the guards of the set, in the order of loading.
+    */
+    pub fn iter(&self) -> std::slice::Iter<'_, KernelGuard<'l>> {
+        self.guards.iter()
+    }
+
+    /**
+    Paths of all the kernels loaded by the set, including the ones listed by metakernels.
+    */
+    pub fn paths(&self) -> Vec<String> {
+        self.guards
+            .iter()
+            .flat_map(|guard| std::iter::once(guard.path.clone()).chain(guard.children()))
+            .collect()
+    }
+}
+
+#[cfg(not(feature = "lock"))]
+impl Default for KernelSet<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for KernelSet<'_> {
+    fn drop(&mut self) {
+        while let Some(guard) = self.guards.pop() {
+            drop(guard);
+        }
+    }
+}
+
+impl<'a, 'l> IntoIterator for &'a KernelSet<'l> {
+    type Item = &'a KernelGuard<'l>;
+    type IntoIter = std::slice::Iter<'a, KernelGuard<'l>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/**
+Type of a kernel, as reported by the KEEPER subsystem.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KernelKind {
+    /// Ephemeris kernel.
+    Spk,
+    /// Orientation kernel.
+    Ck,
+    /// Binary planetary constants kernel.
+    Pck,
+    /// Digital shape kernel.
+    Dsk,
+    /// Events kernel.
+    Ek,
+    /// Text kernel, such as a leapseconds or frames kernel.
+    Text,
+    /// Metakernel.
+    Meta,
+}
+
+impl KernelKind {
+    /**
+    All the kinds of kernels, to list every loaded kernel with [`loaded_kernels`].
+    */
+    pub const ALL: [Self; 7] = [
+        Self::Spk,
+        Self::Ck,
+        Self::Pck,
+        Self::Dsk,
+        Self::Ek,
+        Self::Text,
+        Self::Meta,
+    ];
+
+    /**
+    Name of the kind, as accepted by [`raw::ktotal`].
+    */
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spk => "SPK",
+            Self::Ck => "CK",
+            Self::Pck => "PCK",
+            Self::Dsk => "DSK",
+            Self::Ek => "EK",
+            Self::Text => "TEXT",
+            Self::Meta => "META",
+        }
+    }
+}
+
+impl fmt::Display for KernelKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/**
+Error returned when parsing an unknown [`KernelKind`] name.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown kernel kind {0}")]
+pub struct ParseKernelKindError(String);
+
+impl FromStr for KernelKind {
+    type Err = ParseKernelKindError;
+
+    fn from_str(kind: &str) -> std::result::Result<Self, Self::Err> {
+        match kind.trim() {
+            "SPK" => Ok(Self::Spk),
+            "CK" => Ok(Self::Ck),
+            "PCK" => Ok(Self::Pck),
+            "DSK" => Ok(Self::Dsk),
+            "EK" => Ok(Self::Ek),
+            "TEXT" => Ok(Self::Text),
+            "META" => Ok(Self::Meta),
+            other => Err(ParseKernelKindError(other.to_string())),
+        }
+    }
+}
+
+/**
+A kernel loaded in the KEEPER subsystem.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedKernel {
+    /// Path of the kernel, as given to [`raw::furnsh`] or listed in a metakernel.
+    pub path: String,
+    /// Type of the kernel.
+    pub kind: KernelKind,
+    /// Path of the metakernel which loaded the kernel, if any.
+    pub source: Option<String>,
+    /// Handle of the kernel if it is a binary kernel, `0` otherwise.
+    pub handle: i32,
+}
+
+impl LoadedKernel {
+    /**
+    Look a loaded kernel up by path, or [`None`] if it is not loaded.
+
+    See [kinfo_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kinfo_c.html).
+    */
+    #[with_lock]
+    pub fn find(path: &str) -> Option<Self> {
+        let (kind, source, handle, found) = neat::kinfo(path);
+        if !found {
+            return None;
+        }
+        Self::new(path.to_string(), &kind, source, handle)
+    }
+
+    fn new(path: String, kind: &str, source: String, handle: i32) -> Option<Self> {
+        Some(Self {
+            path,
+            kind: kind.parse().ok()?,
+            source: if source.is_empty() {
+                None
+            } else {
+                Some(source)
+            },
+            handle,
+        })
+    }
+}
+
+/**
+Iterate over the loaded kernels of the given kinds, in the order of loading. Pass
+[`KernelKind::ALL`] to list every loaded kernel. Each kernel is looked up when the iterator reaches
+it; with the `lock` feature, the iterator keeps the [`SpiceLock`][crate::SpiceLock] borrowed.
+
+See [kdata_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kdata_c.html).
+*/
+#[with_lock(unlocked, _lock: &'l crate::SpiceLock)]
+pub fn loaded_kernels<'l>(kinds: &[KernelKind]) -> impl Iterator<Item = LoadedKernel> + 'l {
+    let kinds = kinds
+        .iter()
+        .map(KernelKind::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+    // An empty list of kinds lists no kernel, without asking CSPICE.
+    let total = match kinds.is_empty() {
+        true => 0,
+        false => raw::ktotal(&kinds),
+    };
+    (0..total).filter_map(move |which| {
+        let (path, kind, source, handle, found) = neat::kdata(which, &kinds);
+        if found {
+            LoadedKernel::new(path, &kind, source, handle)
+        } else {
+            None
+        }
+    })
+}
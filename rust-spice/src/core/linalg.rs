@@ -0,0 +1,197 @@
+/*!
+Geometry and vector functions taking and returning nalgebra types.
+
+## Description
+
+The functions of [`raw`] exchange vectors as `[f64; 3]` arrays and matrices as `[[f64; 3]; 3]`
+arrays of rows, as CSPICE does. nalgebra stores matrices column by column, so building a
+[`Matrix3`] from the flattened array of rows silently transposes it. The functions of this module
+wrap their counterparts from [`raw`] and return [`Vector3`], [`Matrix3`] or [`Rotation3`] instead,
+converting the matrices with [`to_matrix`] and [`from_matrix`], which keep the element at row `i`
+and column `j` in place.
+
+SPICE quaternions, as returned by [`raw::m2q`], put the scalar part first and represent the
+rotation of vectors, as do nalgebra quaternions, so [`to_unit_quaternion`] and
+[`from_unit_quaternion`] only reorder the components.
+
+This module is opt-in: its functions are not re-exported at the root of the crate, so that they do
+not shadow the array-based functions of the same name.
+
+With the `lock` feature, the functions calling CSPICE take the [`SpiceLock`][crate::SpiceLock] as
+an additional argument, while the conversions between arrays and nalgebra types do not need it.
+
+See the [nalgebra documentation](https://docs.rs/nalgebra).
+*/
+
+use crate::{raw, Et};
+use na::{Matrix3, Quaternion, Rotation3, UnitQuaternion, Vector3};
+use serde::{Deserialize, Serialize};
+use spice_derive::with_lock;
+
+/**
+Position and velocity of a state, such as returned by [`spkezr`].
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    /// Position, in kilometers.
+    pub position: Vector3<f64>,
+    /// Velocity, in kilometers per second.
+    pub velocity: Vector3<f64>,
+}
+
+impl From<[f64; 6]> for State {
+    fn from(state: [f64; 6]) -> Self {
+        Self {
+            position: Vector3::new(state[0], state[1], state[2]),
+            velocity: Vector3::new(state[3], state[4], state[5]),
+        }
+    }
+}
+
+impl From<State> for [f64; 6] {
+    fn from(state: State) -> Self {
+        let (p, v) = (state.position, state.velocity);
+        [p.x, p.y, p.z, v.x, v.y, v.z]
+    }
+}
+
+/**
+Matrix of an array of rows, as returned by CSPICE.
+*/
+pub fn to_matrix(rows: [[f64; 3]; 3]) -> Matrix3<f64> {
+    Matrix3::from_fn(|i, j| rows[i][j])
+}
+
+/**
+Array of rows of a matrix, as expected by CSPICE.
+*/
+pub fn from_matrix(matrix: &Matrix3<f64>) -> [[f64; 3]; 3] {
+    let mut rows = [[0.0; 3]; 3];
+    for (i, row) in rows.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = matrix[(i, j)];
+        }
+    }
+    rows
+}
+
+/**
+Rotation of an array of rows, as returned by CSPICE, which is assumed to be a rotation matrix.
+*/
+pub fn to_rotation(rows: [[f64; 3]; 3]) -> Rotation3<f64> {
+    Rotation3::from_matrix_unchecked(to_matrix(rows))
+}
+
+/**
+Unit quaternion of a SPICE quaternion, `[cos(θ/2), sin(θ/2) a]` for the rotation of vectors by the
+angle θ about the unit axis a, which is assumed to be normalized.
+*/
+pub fn to_unit_quaternion(q: [f64; 4]) -> UnitQuaternion<f64> {
+    UnitQuaternion::new_unchecked(Quaternion::new(q[0], q[1], q[2], q[3]))
+}
+
+/**
+SPICE quaternion of a unit quaternion, scalar part first.
+*/
+pub fn from_unit_quaternion(q: &UnitQuaternion<f64>) -> [f64; 4] {
+    [q.w, q.i, q.j, q.k]
+}
+
+/**
+Return the position of a target body relative to an observing body, with the one way light time.
+
+See [`raw::spkpos`].
+*/
+#[with_lock]
+pub fn spkpos(
+    targ: &str,
+    et: impl Into<Et>,
+    frame: &str,
+    abcorr: &str,
+    obs: &str,
+) -> (Vector3<f64>, f64) {
+    let (ptarg, lt) = raw::spkpos(targ, et, frame, abcorr, obs);
+    (Vector3::from(ptarg), lt)
+}
+
+/**
+Return the state of a target body relative to an observing body, with the one way light time.
+
+See [`raw::spkezr`].
+*/
+#[with_lock]
+pub fn spkezr(targ: &str, et: impl Into<Et>, frame: &str, abcorr: &str, obs: &str) -> (State, f64) {
+    let (starg, lt) = raw::spkezr(targ, et, frame, abcorr, obs);
+    (State::from(starg), lt)
+}
+
+/**
+Return the rotation from one frame to another at a specified epoch.
+
+See [`raw::pxform`].
+*/
+#[with_lock]
+pub fn pxform(from: &str, to: &str, et: impl Into<Et>) -> Rotation3<f64> {
+    to_rotation(raw::pxform(from, to, et))
+}
+
+/**
+Return the rotation from one frame at a specified epoch to another frame at another specified
+epoch.
+
+See [`raw::pxfrm2`].
+*/
+#[with_lock]
+pub fn pxfrm2(from: &str, to: &str, etfrom: impl Into<Et>, etto: impl Into<Et>) -> Rotation3<f64> {
+    to_rotation(raw::pxfrm2(from, to, etfrom, etto))
+}
+
+/**
+Multiply a 3x3 matrix with a 3-dimensional vector.
+
+See [`raw::mxv`].
+*/
+#[with_lock]
+pub fn mxv(m1: &Matrix3<f64>, vin: &Vector3<f64>) -> Vector3<f64> {
+    Vector3::from(raw::mxv(from_matrix(m1), (*vin).into()))
+}
+
+/**
+Compute the cross product of two 3-dimensional vectors.
+
+See [`raw::vcrss`].
+*/
+#[with_lock]
+pub fn vcrss(v1: &Vector3<f64>, v2: &Vector3<f64>) -> Vector3<f64> {
+    Vector3::from(raw::vcrss((*v1).into(), (*v2).into()))
+}
+
+/**
+Compute the dot product of two 3-dimensional vectors.
+
+See [`raw::vdot`].
+*/
+#[with_lock]
+pub fn vdot(v1: &Vector3<f64>, v2: &Vector3<f64>) -> f64 {
+    raw::vdot((*v1).into(), (*v2).into())
+}
+
+/**
+Find the separation angle in radians between two 3-dimensional vectors.
+
+See [`raw::vsep`].
+*/
+#[with_lock]
+pub fn vsep(v1: &Vector3<f64>, v2: &Vector3<f64>) -> f64 {
+    raw::vsep((*v1).into(), (*v2).into())
+}
+
+/**
+Transpose a 3x3 matrix.
+
+See [`raw::xpose`].
+*/
+#[with_lock]
+pub fn xpose(m1: &Matrix3<f64>) -> Matrix3<f64> {
+    to_matrix(raw::xpose(from_matrix(m1)))
+}
@@ -3,11 +3,14 @@ use std::marker::PhantomData;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 // Atomic bool to keep track of whether an instance exists
-static mut IS_LOCKED: AtomicBool = AtomicBool::new(false);
+static IS_LOCKED: AtomicBool = AtomicBool::new(false);
 
 /// A wrapper singleton struct around the API to prevent concurrent calls to SPICE functions from multiple threads.
 /// Exposes all functions as methods with identical signatures besides the added `&self` argument.
+/// The raw versions of functions which also have a neat version are prefixed with `raw_`, and the
+/// fallible versions of functions are prefixed with `try_`.
 /// Only available with the `lock` feature enabled.
+#[derive(Debug)]
 pub struct SpiceLock {
     // Private dummy field. Prevents direct instantiation and makes type `!Sync` (because `Cell` is `!Sync`)
     _x: PhantomData<Cell<()>>,
@@ -19,11 +22,9 @@ impl SpiceLock {
     pub fn try_acquire() -> Result<Self, &'static str> {
         // Sets value equal to `true` if it was `false` and
         // returns a result with the previous value (`Ok` if swapped, `Err` if not)
-        let was_unlocked = unsafe {
-            IS_LOCKED
-                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
-                .is_ok()
-        };
+        let was_unlocked = IS_LOCKED
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok();
         // If the value was changed, it was atomically set to true and no instance exists
         if was_unlocked {
             // Safely return the only instance
@@ -37,8 +38,6 @@ impl SpiceLock {
 
 impl Drop for SpiceLock {
     fn drop(&mut self) {
-        unsafe {
-            IS_LOCKED.store(false, Ordering::Release);
-        }
+        IS_LOCKED.store(false, Ordering::Release);
     }
 }
@@ -13,22 +13,71 @@ In the meantime, if you are in a rush and need quickly to use a function not imp
 Rust interface, use the unsafe C functions [here][crate::c#functions]. You can find some inspiration in
 the source of this lib to deal with the FFI types and unsafe code.
 
+## Errors
+
+By default, CSPICE aborts the program when an error is signaled. The functions of [`fallible`]
+return a [`Result`] carrying a [`SpiceError`] instead, see [`error`] for the details.
+
+## Kernels
+
+Kernels loaded with [`Kernel::load`] stay loaded as long as the returned [`KernelGuard`] lives, see
+[`kernel`] for the details.
+
+The variables of the kernel pool are read and written with [`pool`].
+
+## Frames
+
+The reference frames known to CSPICE are looked up with [`Frame`], see [`frame`] for the details.
+
+## Coordinates
+
+Positions and states are converted between coordinate systems with [`Coordinates`], see
+[`coordinates`] for the details.
+
+## nalgebra
+
+The geometry and vector functions of [`linalg`] take and return nalgebra types instead of arrays.
+The quaternions of CSPICE, such as returned by [`m2q`], convert to nalgebra unit quaternions with
+[`linalg::to_unit_quaternion`].
+
 ## Bindings
 
 CSPICE | **rust-spice** | Description
 -------|--------------|------------
+[appndc_c][appndc_c link] | [`Cell::push`] | Append an item to a character cell
+[appndd_c][appndd_c link] | [`Cell::push`] | Append an item to a double precision cell
+[appndi_c][appndi_c link] | [`Cell::push`] | Append an item to an integer cell
+[axisar_c][axisar_c link] | [`raw::axisar`] | Axis and angle to rotation
+[azlrec_c][azlrec_c link] | [`raw::azlrec`] | Az/El to rectangular coordinates
 [bodc2n_c][bodc2n_c link] | [`neat::bodc2n`] | Body ID code to name translation
 [bodfnd_c][bodfnd_c link] | [`raw::bodfnd`] | Find values from the kernel pool
 [bodn2c_c][bodn2c_c link] | [`raw::bodn2c`] | Body name to ID code translation
 [bodvrd_c][bodvrd_c link] | [`raw::bodvrd`] | Return d.p. values from the kernel pool
-[ckcov_c][ckcov_c link] | *TODO*
-[ckgp_c][ckgp_c link] | *TODO*
-[ckgpav_c][ckgpav_c link] | *TODO*
-[ckobj_c][ckobj_c link] | *TODO*
+[ccifrm_c][ccifrm_c link] | [`neat::ccifrm`] | Class and class ID to associated frame
+[cidfrm_c][cidfrm_c link] | [`neat::cidfrm`] | Center ID to associated frame
+[ckcov_c][ckcov_c link] | [`raw::ckcov`] | CK coverage
+[ckgp_c][ckgp_c link] | [`neat::ckgp`] | CK pointing
+[ckgpav_c][ckgpav_c link] | [`neat::ckgpav`] | CK pointing and angular velocity
+[ckobj_c][ckobj_c link] | [`raw::ckobj`] | CK objects
+[cnmfrm_c][cnmfrm_c link] | [`neat::cnmfrm`] | Center name to associated frame
+[cvpool_c][cvpool_c link] | [`raw::cvpool`] | Check variable in the pool for update
+[cylrec_c][cylrec_c link] | [`raw::cylrec`] | Cylindrical to rectangular
 [dascls_c][dascls_c link] | [`raw::dascls`] | DAS, close file
 [dasopr_c][dasopr_c link] | [`raw::dasopr`] | DAS, open for read
+[dazldr_c][dazldr_c link] | [`raw::dazldr`] | Derivative of Az/El w.r.t. rectangular
+[dcyldr_c][dcyldr_c link] | [`raw::dcyldr`] | Derivative of cylindrical w.r.t. rectangular
 [deltet_c][deltet_c link] | [`raw::udeltet`] | Delta ET, ET - UTC
+[dgeodr_c][dgeodr_c link] | [`raw::dgeodr`] | Derivative of geodetic w.r.t. rectangular
+[diff_c][diff_c link] | [`Cell::difference`] | Difference of two sets
 [dlabfs_c][dlabfs_c link] | [`raw::dlabfs`] | DLA, begin forward search
+[dlatdr_c][dlatdr_c link] | [`raw::dlatdr`] | Derivative of latitudinal w.r.t. rectangular
+[dpgrdr_c][dpgrdr_c link] | [`raw::dpgrdr`] | Derivative of planetographic w.r.t. rectangular
+[drdazl_c][drdazl_c link] | [`raw::drdazl`] | Derivative of rectangular w.r.t. Az/El
+[drdcyl_c][drdcyl_c link] | [`raw::drdcyl`] | Derivative of rectangular w.r.t. cylindrical
+[drdgeo_c][drdgeo_c link] | [`raw::drdgeo`] | Derivative of rectangular w.r.t. geodetic
+[drdlat_c][drdlat_c link] | [`raw::drdlat`] | Derivative of rectangular w.r.t. latitudinal
+[drdpgr_c][drdpgr_c link] | [`raw::drdpgr`] | Derivative of rectangular w.r.t. planetographic
+[drdsph_c][drdsph_c link] | [`raw::drdsph`] | Derivative of rectangular w.r.t. spherical
 [dskgd_c][dskgd_c link] | [`raw::dskgd`] | DSK, return DSK segment descriptor
 [dskn02_c][dskn02_c link] | [`raw::dskn02`] | DSK, type 2, compute normal vector for plate
 [dskobj_c][dskobj_c link] | [`raw::dskobj`] | DSK, get object IDs
@@ -37,38 +86,87 @@ CSPICE | **rust-spice** | Description
 [dskv02_c][dskv02_c link] | [`neat::dskv02`] | DSK, fetch type 2 vertex data
 [dskx02_c][dskx02_c link] | [`raw::dskx02`] | DSK, ray-surface intercept, type 2
 [dskz02_c][dskz02_c link] | [`raw::dskz02`] | DSK, fetch type 2 model size parameters
+[dsphdr_c][dsphdr_c link] | [`raw::dsphdr`] | Derivative of spherical w.r.t. rectangular
+[dtpool_c][dtpool_c link] | [`raw::dtpool`] | Data for a kernel pool variable
+[et2lst_c][et2lst_c link] | [`neat::et2lst`] | ET to Local Solar Time
+[et2utc_c][et2utc_c link] | [`neat::et2utc`] | Ephemeris Time to UTC
+[etcal_c][etcal_c link] | [`neat::etcal`] | Convert ET to Calendar format
+[eul2m_c][eul2m_c link] | [`raw::eul2m`] | Euler angles to matrix
+[frinfo_c][frinfo_c link] | [`raw::frinfo`] | Frame Information
+[frmnam_c][frmnam_c link] | [`neat::frmnam`] | Frame to Name
 [furnsh_c][furnsh_c link] | [`raw::furnsh`] | Furnish a program with SPICE kernels
-[gcpool_c][gcpool_c link] | *TODO*
+[gcpool_c][gcpool_c link] | [`raw::gcpool`] | Get character data from the kernel pool
 [gdpool_c][gdpool_c link] | [`raw::gdpool`] | Get d.p. values from the kernel pool
 [georec_c][georec_c link] | [`raw::georec`] | Geodetic to rectangular coordinates
 [getfov_c][getfov_c link] | [`raw::getfov`] | Get instrument FOV parameters
-[gipool_c][gipool_c link] | *TODO*
+[gfdist_c][gfdist_c link] | [`raw::gfdist`] | GF, distance search
+[gfilum_c][gfilum_c link] | [`raw::gfilum`] | GF, illumination angle search
+[gfoclt_c][gfoclt_c link] | [`raw::gfoclt`] | GF, find occultation
+[gfpa_c][gfpa_c link] | [`raw::gfpa`] | GF, phase angle search
+[gfposc_c][gfposc_c link] | [`raw::gfposc`] | GF, observer-target vector coordinate search
+[gfrfov_c][gfrfov_c link] | [`raw::gfrfov`] | GF, is ray in FOV?
+[gfrr_c][gfrr_c link] | [`raw::gfrr`] | GF, range rate search
+[gfsep_c][gfsep_c link] | [`raw::gfsep`] | GF, angular separation search
+[gfsntc_c][gfsntc_c link] | [`raw::gfsntc`] | GF, surface intercept vector coordinate search
+[gfsubc_c][gfsubc_c link] | [`raw::gfsubc`] | GF, subpoint vector coordinate search
+[gftfov_c][gftfov_c link] | [`raw::gftfov`] | GF, is target in FOV?
+[gfudb_c][gfudb_c link] | [`raw::gfudb`] | GF, user defined boolean
+[gfuds_c][gfuds_c link] | [`raw::gfuds`] | GF, user defined scalar
+[gipool_c][gipool_c link] | [`raw::gipool`] | Get integers from the kernel pool
+[gnpool_c][gnpool_c link] | [`raw::gnpool`] | Get names of kernel pool variables
 [illumf_c][illumf_c link] | [`raw::illumf`] | Illumination angles, general source, return flags
+[insrtc_c][insrtc_c link] | [`Cell::insert`] | Insert an item into a character set
+[insrtd_c][insrtd_c link] | [`Cell::insert`] | Insert an item into a double precision set
+[insrti_c][insrti_c link] | [`Cell::insert`] | Insert an item into an integer set
+[inter_c][inter_c link] | [`Cell::intersection`] | Intersect two sets
 [kclear_c][kclear_c link] | [`raw::kclear`] | Keeper clear
 [kdata_c][kdata_c link] | [`neat::kdata`] | Kernel Data
+[kinfo_c][kinfo_c link] | [`neat::kinfo`] | Kernel Information
 [ktotal_c][ktotal_c link] | [`raw::ktotal`] | Kernel Totals
 [latrec_c][latrec_c link] | [`raw::latrec`] | Latitudinal to rectangular coordinates
 [latsrf_c][latsrf_c link] | *TODO*
+[m2eul_c][m2eul_c link] | [`raw::m2eul`] | Matrix to Euler angles
+[m2q_c][m2q_c link] | [`raw::m2q`] | Matrix to quaternion
 [mxv_c][mxv_c link] | [`raw::mxv`] |  Matrix times vector, 3x3
+[namfrm_c][namfrm_c link] | [`raw::namfrm`] | Name to frame
 [occult_c][occult_c link] | [`raw::occult`] | Find occultation type at time
-[pckcov_c][pckcov_c link] | *TODO*
+[pckcov_c][pckcov_c link] | [`raw::pckcov`] | PCK coverage
+[pcpool_c][pcpool_c link] | [`raw::pcpool`] | Put character strings into the kernel pool
+[pdpool_c][pdpool_c link] | [`raw::pdpool`] | Put d.p.'s into the kernel pool
+[pgrrec_c][pgrrec_c link] | [`raw::pgrrec`] | Planetographic to rectangular
+[pipool_c][pipool_c link] | [`raw::pipool`] | Put integers into the kernel pool
 [pxform_c][pxform_c link] | [`raw::pxform`] | Position Transformation Matrix
 [pxfrm2_c][pxfrm2_c link] | [`raw::pxfrm2`] | Position Transform Matrix, Different Epochs
-[sce2c_c][sce2c_c link] | *TODO*
-[sce2s_c][sce2s_c link] | *TODO*
-[scencd_c][scencd_c link] | *TODO*
-[scdecd_c][scdecd_c link] | *TODO*
-[scs2e_c][scs2e_c link] | *TODO*
-[sct2e_c][sct2e_c link] | *TODO*
+[q2m_c][q2m_c link] | [`raw::q2m`] | Quaternion to matrix
+[qdq2av_c][qdq2av_c link] | [`raw::qdq2av`] | Quaternion and quaternion derivative to angular velocity
+[qxq_c][qxq_c link] | [`raw::qxq`] | Quaternion times quaternion
+[rav2xf_c][rav2xf_c link] | [`raw::rav2xf`] | Rotation and angular velocity to transform
+[raxisa_c][raxisa_c link] | [`raw::raxisa`] | Rotation axis of a matrix
+[recazl_c][recazl_c link] | [`raw::recazl`] | Rectangular coordinates to Az/El
+[reccyl_c][reccyl_c link] | [`raw::reccyl`] | Rectangular to cylindrical coordinates
+[recgeo_c][recgeo_c link] | [`raw::recgeo`] | Rectangular to geodetic
+[reclat_c][reclat_c link] | [`raw::reclat`] | Rectangular to latitudinal coordinates
+[recsph_c][recsph_c link] | [`raw::recsph`] | Rectangular to spherical coordinates
+[rotate_c][rotate_c link] | [`raw::rotate`] | Generate a rotation matrix
+[rotmat_c][rotmat_c link] | [`raw::rotmat`] | Rotate a matrix
+[sce2c_c][sce2c_c link] | [`raw::sce2c`] | ET to continuous SCLK ticks
+[sce2s_c][sce2s_c link] | [`neat::sce2s`] | ET to SCLK string
+[sce2t_c][sce2t_c link] | [`raw::sce2t`] | ET to SCLK ticks
+[scencd_c][scencd_c link] | [`raw::scencd`] | Encode spacecraft clock
+[scdecd_c][scdecd_c link] | [`neat::scdecd`] | Decode spacecraft clock
+[scpart_c][scpart_c link] | [`raw::scpart`] | Spacecraft Clock Partition Information
+[scs2e_c][scs2e_c link] | [`raw::scs2e`] | SCLK string to ET
+[sct2e_c][sct2e_c link] | [`raw::sct2e`] | SCLK ticks to ET
 [sincpt_c][sincpt_c link] | [`raw::sincpt`] | Surface intercept
+[sphrec_c][sphrec_c link] | [`raw::sphrec`] | Spherical to rectangular coordinates
 [spkcls_c][spkcov_c link] | [`raw::spkcls`] | SPK, Close file
-[spkcov_c][spkcov_c link] | *TODO*
+[spkcov_c][spkcov_c link] | [`raw::spkcov`] | SPK coverage
 [spkcpo_c][spkcpo_c link] | *TODO*
 [spkcpt_c][spkcpt_c link] | *TODO*
 [spkcvo_c][spkcvo_c link] | *TODO*
 [spkcvt_c][spkcvt_c link] | *TODO*
 [spkezr_c][spkezr_c link] | [`raw::spkezr`] | S/P Kernel, easier reader
-[spkobj_c][spkobj_c link] | *TODO*
+[spkobj_c][spkobj_c link] | [`raw::spkobj`] | SPK objects
 [spkopn_c][spkopn_c link] | [`raw::spkopn`] | SPK, open new file.
 [spkpos_c][spkpos_c link] | [`raw::spkpos`] | S/P Kernel, position
 [spkw09_c][spkopn_c link] | [`raw::spkw09`] | Write SPK segment, type 9
@@ -80,30 +178,69 @@ CSPICE | **rust-spice** | Description
 [str2et_c][str2et_c link] | [`raw::str2et`] | String to ET
 [sunpnt_c][sxform_c link] | [`raw::subpnt`] | Sub-observer point
 [surfpt_c][surfpt_c link] | [`raw::surfpt`] | Surface point on an ellipsoid
-[sxform_c][sxform_c link] | *TODO*
+[swpool_c][swpool_c link] | [`raw::swpool`] | Set watch on a pool variable
+[sxform_c][sxform_c link] | [`raw::sxform`] | State transformation matrix
 [radrec_c][radrec_c link] | [`raw::radrec`] |  RA and DEC to rectangular coordinates
 [recrad_c][recrad_c link] | [`raw::recrad`] | Rectangular coordinates to RA and DEC
 [recpgr_c][recpgr_c link] | [`raw::recpgr`] | Rectangular to planetographic
+[timdef_c][timdef_c link] | [`neat::timdef`] | Time Software Defaults
 [timout_c][timout_c link] | [`neat::timout`] | Time Output
+[tparse_c][tparse_c link] | [`neat::tparse`] | Parse a UTC time string
+[tpictr_c][tpictr_c link] | [`neat::tpictr`] | Create a Time Format Picture
+[twovec_c][twovec_c link] | [`raw::twovec`] | Two vectors defining an orthonormal frame
+[union_c][union_c link] | [`Cell::union`] | Union two sets
 [unitim_c][unitim_c link] | [`raw::unitime`] | Uniform time scale transformation
 [unload_c][unload_c link] | [`raw::unload`] | Unload a kernel
+[utc2et_c][utc2et_c link] | [`raw::utc2et`] | UTC to Ephemeris Time
+[valid_c][valid_c link] | [`Cell::validate`] | Validate a set
 [vcrss_c][vcrss_c link] | [`raw::vcrss`] | Vector cross product, 3 dimensions
 [vdot_c][vdot_c link] | [`raw::vdot`] |  Vector dot product, 3 dimensions
 [vsep_c][vsep_c link] | [`raw::vsep`] | Angular separation of vectors, 3 dimensions
+[wncond_c][wncond_c link] | [`Window::contract`] | Contract the intervals of a DP window
+[wndifd_c][wndifd_c link] | [`Window::difference`] | Difference two DP windows
+[wnexpd_c][wnexpd_c link] | [`Window::expand`] | Expand the intervals of a DP window
+[wnfild_c][wnfild_c link] | [`Window::fill_gaps`] | Fill small gaps in a DP window
+[wnfltd_c][wnfltd_c link] | [`Window::filter_small`] | Filter small intervals from a DP window
+[wninsd_c][wninsd_c link] | [`Window::insert_interval`] | Insert an interval into a DP window
+[wnintd_c][wnintd_c link] | [`Window::intersect`] | Intersect two DP windows
+[wnunid_c][wnunid_c link] | [`Window::union`] | Union two DP windows
+[xf2rav_c][xf2rav_c link] | [`raw::xf2rav`] | Transform to rotation and angular velocity
 [xpose_c][xpose_c link] | [`raw::xpose`] | Transpose a matrix, 3x3
 
+[appndc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/appndc_c.html
+[appndd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/appndd_c.html
+[appndi_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/appndi_c.html
+[axisar_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/axisar_c.html
+[azlrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/azlrec_c.html
 [bodc2n_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodc2n_c.html
 [bodfnd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodfnd_c.html
 [bodn2c_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodn2c_c.html
 [bodvrd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/bodvrd_c.html
+[ccifrm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ccifrm_c.html
+[cidfrm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cidfrm_c.html
 [ckcov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckcov_c.html
 [ckgp_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckgp_c.html
 [ckgpav_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckgpav_c.html
 [ckobj_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ckobj_c.html
+[cnmfrm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cnmfrm_c.html
+[cvpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cvpool_c.html
+[cylrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cylrec_c.html
 [dascls_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dascls_c.html
 [dasopr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dasopr_c.html
+[dazldr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dazldr_c.html
+[dcyldr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dcyldr_c.html
 [deltet_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/deltet_c.html
+[dgeodr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dgeodr_c.html
+[diff_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/diff_c.html
 [dlabfs_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dasopr_c.html
+[dlatdr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dlatdr_c.html
+[dpgrdr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dpgrdr_c.html
+[drdazl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdazl_c.html
+[drdcyl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdcyl_c.html
+[drdgeo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdgeo_c.html
+[drdlat_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdlat_c.html
+[drdpgr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdpgr_c.html
+[drdsph_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/drdsph_c.html
 [dskgd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskgd_c.html
 [dskn02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskn02_c.html
 [dskobj_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskobj_c.html
@@ -112,31 +249,80 @@ CSPICE | **rust-spice** | Description
 [dskv02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskv02_c.html
 [dskx02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskx02_c.html
 [dskz02_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dskz02_c.html
+[dsphdr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dsphdr_c.html
+[dtpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dtpool_c.html
+[et2lst_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/et2lst_c.html
+[et2utc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/et2utc_c.html
+[etcal_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/etcal_c.html
+[eul2m_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/eul2m_c.html
+[frinfo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/frinfo_c.html
+[frmnam_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/frmnam_c.html
 [furnsh_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/furnsh_c.html
 [gcpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gcpool_c.html
 [gdpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gdpool_c.html
 [getfov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/getfov_c.html
 [georec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/georec_c.html
+[gfdist_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfdist_c.html
+[gfilum_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfilum_c.html
+[gfoclt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfoclt_c.html
+[gfpa_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfpa_c.html
+[gfposc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfposc_c.html
+[gfrfov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfrfov_c.html
+[gfrr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfrr_c.html
+[gfsep_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfsep_c.html
+[gfsntc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfsntc_c.html
+[gfsubc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfsubc_c.html
+[gftfov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gftfov_c.html
+[gfudb_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfudb_c.html
+[gfuds_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gfuds_c.html
 [gipool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gipool_c.html
+[gnpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gnpool_c.html
 [illumf_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/illumf_c.html
+[insrtc_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/insrtc_c.html
+[insrtd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/insrtd_c.html
+[insrti_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/insrti_c.html
+[inter_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/inter_c.html
 [kclear_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kclear_c.html
 [kdata_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kdata_c.html
+[kinfo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/kinfo_c.html
 [ktotal_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/ktotal_c.html
 [latrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/latrec_c.html
 [latsrf_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/latsrf_c.html
+[m2eul_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/m2eul_c.html
+[m2q_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/m2q_c.html
 [mxv_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/mxv_c.html
+[namfrm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/namfrm_c.html
 [occult_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/occult_c.html
+[pcpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pcpool_c.html
+[pdpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pdpool_c.html
+[pgrrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pgrrec_c.html
+[pipool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pipool_c.html
 [pxform_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pxform_c.html
 [pckcov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pckcov_c.html
 [pckfrm_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pckfrm_c.html
 [pxfrm2_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pxfrm2_c.html
+[q2m_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/q2m_c.html
+[qdq2av_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/qdq2av_c.html
+[qxq_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/qxq_c.html
+[rav2xf_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/rav2xf_c.html
+[raxisa_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/raxisa_c.html
+[recazl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recazl_c.html
+[reccyl_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/reccyl_c.html
+[recgeo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recgeo_c.html
+[reclat_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/reclat_c.html
+[recsph_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recsph_c.html
+[rotate_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/rotate_c.html
+[rotmat_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/rotmat_c.html
 [scdecd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scdecd_c.html
 [sce2c_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2c_c.html
 [sce2s_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2s_c.html
+[sce2t_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2t_c.html
 [scencd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scencd_c.html
+[scpart_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scpart_c.html
 [scs2e_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scs2e_c.html
 [sct2e_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sct2e_c.html
 [sincpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sincpt_c.html
+[sphrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sphrec_c.html
 [spkcls_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcls_c.html
 [spkcpo_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcpo_c.html
 [spkcov_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/spkcov_c.html
@@ -156,32 +342,113 @@ CSPICE | **rust-spice** | Description
 [str2et_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/str2et_c.html
 [subpnt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/subpnt_c.html
 [surfpt_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/surfpt_c.html
+[swpool_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/swpool_c.html
 [sxform_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sxform_c.html
 [radrec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/radrec_c.html
 [recrad_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recrad_c.html
 [recpgr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/recpgr_c.html
+[timdef_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/timdef_c.html
 [timout_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/timout_c.html
+[tparse_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/tparse_c.html
+[tpictr_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/tpictr_c.html
+[twovec_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/twovec_c.html
+[union_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/union_c.html
 [unitim_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unitim_c.html
 [unload_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unload_c.html
+[utc2et_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/utc2et_c.html
+[valid_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/valid_c.html
 [vcrss_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vcrss_c.html
 [vdot_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vdot_c.html
 [vsep_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/vsep_c.html
+[wncond_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wncond_c.html
+[wndifd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wndifd_c.html
+[wnexpd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnexpd_c.html
+[wnfild_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnfild_c.html
+[wnfltd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnfltd_c.html
+[wninsd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wninsd_c.html
+[wnintd_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnintd_c.html
+[wnunid_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnunid_c.html
+[xf2rav_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/xf2rav_c.html
 [xpose_c link]: https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/xpose_c.html
 */
 
+// With the `lock` feature, the re-exports below are only visible to the crate, which does not use
+// all of them
+#![cfg_attr(feature = "lock", allow(unused_imports))]
+
+/**
+Lock kept borrowed by the values calling CSPICE after they are built, such as the kernel guards.
+Without the `lock` feature, only its lifetime is kept.
+*/
+#[cfg(feature = "lock")]
+pub(crate) type LockRef<'l> = &'l lock::SpiceLock;
+#[cfg(not(feature = "lock"))]
+pub(crate) type LockRef<'l> = std::marker::PhantomData<&'l ()>;
+
+/**
+[`LockRef`] to the lock given as argument, ignored without the `lock` feature.
+*/
+#[cfg(feature = "lock")]
+macro_rules! lock_ref {
+    ($lock:ident) => {
+        $lock
+    };
+}
+#[cfg(not(feature = "lock"))]
+macro_rules! lock_ref {
+    ($lock:ident) => {
+        std::marker::PhantomData
+    };
+}
+
 #[cfg(any(feature = "lock", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "lock")))]
 pub mod lock;
+#[cfg(any(feature = "lock", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "lock")))]
+pub mod service;
 
+pub mod cell;
+pub mod coordinates;
+pub mod error;
+pub mod fallible;
+pub mod frame;
+pub mod kernel;
+pub mod linalg;
 pub mod neat;
+pub mod pool;
 pub mod raw;
+pub mod sclk;
+pub mod time;
+pub mod window;
+
+pub use self::cell::{Cell, CellElement};
+pub use self::coordinates::Coordinates;
+pub use self::error::{Result, SpiceError};
+pub use self::frame::{Frame, FrameClass};
+pub use self::kernel::{
+    loaded_kernels, Kernel, KernelGuard, KernelKind, KernelSet, LoadedKernel, ParseKernelKindError,
+};
+pub use self::sclk::Sclk;
+pub use self::time::Et;
+pub use self::window::Window;
 
-pub use self::neat::{bodc2n, dskp02, dskv02, kdata, timout};
+pub use self::neat::{
+    bodc2n, ccifrm, cidfrm, ckcov_loaded, ckgp, ckgpav, ckobj_loaded, cnmfrm, dskp02, dskv02,
+    et2lst, et2utc, etcal, frmnam, kdata, kinfo, lst2et, pckcov_loaded, scdecd, sce2s,
+    spkcov_loaded, spkobj_loaded, sxform_state, timdef, timout, tparse, tpictr,
+};
 pub use self::raw::{
-    bodfnd, bodn2c, bodvrd, dascls, dasopr, deltet, dlabfs, dskgd, dskn02, dskobj, dskx02, dskz02,
-    furnsh, gdpool, georec, getfov, illumf, kclear, ktotal, latrec, mxv, occult, pxform, pxfrm2,
-    radrec, recpgr, recrad, sincpt, spkcls, spkezr, spkopn, spkpos, spkw09, str2et, subpnt, surfpt,
-    unitim, unload, vcrss, vdot, vsep, xpose, DLADSC, DSKDSC,
+    axisar, azlrec, bodfnd, bodn2c, bodvrd, ckcov, ckobj, cvpool, cylrec, dascls, dasopr, dazldr,
+    dcyldr, deltet, dgeodr, dlabfs, dlatdr, dpgrdr, drdazl, drdcyl, drdgeo, drdlat, drdpgr, drdsph,
+    dskgd, dskn02, dskobj, dskx02, dskz02, dsphdr, dtpool, eul2m, frinfo, furnsh, gcpool, gdpool,
+    georec, getfov, gfdist, gfilum, gfoclt, gfpa, gfposc, gfrfov, gfrr, gfsep, gfsntc, gfsubc,
+    gftfov, gfudb, gfuds, gipool, gnpool, illumf, kclear, ktotal, latrec, m2eul, m2q, mxv, namfrm,
+    occult, pckcov, pcpool, pdpool, pgrrec, pipool, pxform, pxfrm2, q2m, qdq2av, qxq, radrec,
+    rav2xf, raxisa, recazl, reccyl, recgeo, reclat, recpgr, recrad, recsph, rotate, rotmat, sce2c,
+    sce2t, scencd, scpart, scs2e, sct2e, sincpt, sphrec, spkcls, spkcov, spkezr, spkobj, spkopn,
+    spkpos, spkw09, str2et, subpnt, surfpt, swpool, sxform, twovec, unitim, unload, utc2et, vcrss,
+    vdot, vsep, xf2rav, xpose, DLADSC, DSKDSC,
 };
 
 /**
@@ -200,45 +467,61 @@ Maximum size of string outputs.
 pub const MAX_LEN_OUT: usize = 256;
 
 /**
-Allocate for a given type and number of elements.
+Allocate a buffer of zeros for a given type and number of elements.
+
+The buffer is a [`Vec`] owned by the caller, which is freed when it goes out of scope. Send it to
+CSPICE with [`mptr`].
 */
 #[macro_export]
 macro_rules! malloc {
     ($a:ty, $n:expr) => {
-        unsafe { libc::malloc(std::mem::size_of::<$a>() * $n as usize) as *mut $a }
+        vec![<$a>::default(); $n as usize]
     };
 }
 
 /**
-Allocate [`*mut i8`][`std::os::raw::c_char`] to be sent as a pointer to a string.
+Allocate a buffer of [`c_char`][`std::os::raw::c_char`] to be sent as a pointer to a string of
+given length, null terminator included.
 */
 #[macro_export]
 macro_rules! mallocstr {
     ($s:expr) => {
-        $crate::malloc!(i8, $s + 1)
+        $crate::malloc!(std::os::raw::c_char, $s as usize + 1)
     };
 }
 
 /**
-Convert [`String`] to [`*mut i8`][`std::os::raw::c_char`].
+Convert [`String`] to a null-terminated buffer of [`c_char`][`std::os::raw::c_char`], to be sent
+with [`mptr`].
+
+The buffer must be kept alive for the duration of the call to CSPICE.
 */
 #[macro_export]
 macro_rules! cstr {
     ($s:expr) => {{
-        std::ffi::CString::new($s).unwrap().into_raw()
+        std::ffi::CString::new($s)
+            .unwrap()
+            .into_bytes_with_nul()
+            .into_iter()
+            .map(|b| b as std::os::raw::c_char)
+            .collect::<Vec<std::os::raw::c_char>>()
     }};
     () => {{
-        std::ffi::CString::new(String::new()).unwrap().into_raw()
+        $crate::cstr!("")
     }};
 }
 
 /**
-Get [`String`] from [`*mut i8`][`std::os::raw::c_char`].
+Get [`String`] from a buffer allocated with [`mallocstr`].
 */
 #[macro_export]
 macro_rules! fcstr {
     ($s:expr) => {{
-        unsafe { std::ffi::CStr::from_ptr($s).to_str().unwrap().to_string() }
+        let s: &[std::os::raw::c_char] = &$s;
+        assert!(s.contains(&0), "string buffer is not null-terminated");
+        unsafe { std::ffi::CStr::from_ptr(s.as_ptr()) }
+            .to_string_lossy()
+            .into_owned()
     }};
 }
 
@@ -13,8 +13,12 @@ an idiomatic usage. The improvements consists in functions:
 + which outputs string that be allocated from default length sometimes
 */
 
+use crate::core::error::{self, try_call, SpiceError};
+use crate::core::kernel::{loaded_kernels_unlocked, KernelKind};
+use crate::core::linalg::to_rotation;
 use crate::raw;
-use crate::MAX_LEN_OUT;
+use crate::{Cell, Et, Window, MAX_LEN_OUT};
+use na::{Rotation3, Vector3};
 #[cfg(any(feature = "lock", doc))]
 use {crate::SpiceLock, spice_derive::impl_for};
 
@@ -28,6 +32,47 @@ pub fn bodc2n(code: i32) -> (String, bool) {
     raw::bodc2n(code, MAX_LEN_OUT as i32)
 }
 
+/**
+Return the frame name, frame ID, and center associated with a given frame class and class ID.
+
+See [`raw::ccifrm`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn ccifrm(frclss: i32, clssid: i32) -> (i32, String, i32, bool) {
+    raw::ccifrm(frclss, clssid, MAX_LEN_OUT as i32)
+}
+
+/**
+Retrieve frame ID code and name to associate with a frame center.
+
+See [`raw::cidfrm`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn cidfrm(cent: i32) -> (i32, String, bool) {
+    raw::cidfrm(cent, MAX_LEN_OUT as i32)
+}
+
+/**
+Retrieve frame ID code and name to associate with an object.
+
+See [`raw::cnmfrm`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn cnmfrm(cname: &str) -> (i32, String, bool) {
+    raw::cnmfrm(cname, MAX_LEN_OUT as i32)
+}
+
+/**
+Retrieve the name of a reference frame associated with a SPICE ID code, or an empty string if
+there is none.
+
+See [`raw::frmnam`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn frmnam(frcode: i32) -> String {
+    raw::frmnam(frcode, MAX_LEN_OUT as i32)
+}
+
 /**
 This routine converts an input epoch represented in TDB seconds past the TDB epoch of J2000 to a
 character string formatted to the specifications of a user's format picture.
@@ -35,10 +80,299 @@ character string formatted to the specifications of a user's format picture.
 See [`raw::timout`] for the raw interface.
 */
 #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-pub fn timout(et: f64, pictur: &str) -> String {
+pub fn timout(et: impl Into<Et>, pictur: &str) -> String {
     raw::timout(et, pictur, pictur.len())
 }
 
+/**
+Get the pointing of an instrument or spacecraft structure at a spacecraft clock time, within a
+tolerance in ticks, as the C-matrix rotating vectors from the reference frame to the instrument
+frame, and the clock time of the pointing found, or [`None`] if no pointing is available.
+
+See [`raw::ckgp`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn ckgp(inst: i32, sclkdp: f64, tol: f64, ref_: &str) -> Option<(Rotation3<f64>, f64)> {
+    match raw::ckgp(inst, sclkdp, tol, ref_) {
+        (cmat, clkout, true) => Some((to_rotation(cmat), clkout)),
+        _ => None,
+    }
+}
+
+/**
+Get the pointing of an instrument or spacecraft structure at a spacecraft clock time, within a
+tolerance in ticks, as the C-matrix rotating vectors from the reference frame to the instrument
+frame, the angular velocity of the instrument relative to the reference frame, in radians per
+second, and the clock time of the pointing found, or [`None`] if no pointing with angular velocity
+is available.
+
+See [`raw::ckgpav`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn ckgpav(
+    inst: i32,
+    sclkdp: f64,
+    tol: f64,
+    ref_: &str,
+) -> Option<(Rotation3<f64>, Vector3<f64>, f64)> {
+    match raw::ckgpav(inst, sclkdp, tol, ref_) {
+        (cmat, av, clkout, true) => Some((to_rotation(cmat), Vector3::from(av), clkout)),
+        _ => None,
+    }
+}
+
+/**
+Size of the local solar time strings returned by [`et2lst`].
+*/
+const LST_LEN: usize = 16;
+
+/**
+Number of seconds in a local solar day, as counted by [`et2lst`].
+*/
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/**
+Precision in seconds of ephemeris time of the epochs found by [`lst2et`].
+*/
+const LST_TOLERANCE: f64 = 1e-3;
+
+/**
+Maximum number of steps taken by [`lst2et`] before giving up.
+*/
+const LST_MAX_STEPS: usize = 100_000;
+
+/**
+Given an ephemeris epoch, compute the local solar time for an object on the surface of a body at a
+specified longitude, in radians.
+
+See [`raw::et2lst`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn et2lst(
+    et: impl Into<Et>,
+    body: i32,
+    lon: f64,
+    lon_type: &str,
+) -> (i32, i32, i32, String, String) {
+    raw::et2lst(et, body, lon, lon_type, LST_LEN, LST_LEN)
+}
+
+/**
+Find the first epoch, not earlier than `after`, at which the local solar time computed by
+[`et2lst`] reaches `time`, given as hours, minutes and seconds.
+
+The local time is sampled every `step` seconds of ephemeris time, which must be shorter than half
+the local solar day of the body, then refined by bisection to the millisecond.
+
+Returns an error if `step` is not positive or not shorter than half the rotation period of the body
+given by its PCK, if `time` is not a valid time of day, if `time` is not reached within 100 000
+steps, or if a sample signals an error, for instance when no PCK is loaded for the body. The
+samples are taken with the error action set to `RETURN`, so the error is returned whatever the
+error action is.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn lst2et(
+    body: i32,
+    lon: f64,
+    lon_type: &str,
+    time: (i32, i32, i32),
+    after: impl Into<Et>,
+    step: f64,
+) -> error::Result<Et> {
+    if !(step.is_finite() && step > 0.0) {
+        return Err(lst2et_error(
+            "SPICE(INVALIDSTEP)",
+            format!("The step {} must be positive.", step),
+        ));
+    }
+    let (hr, mn, sc) = time;
+    if !((0..24).contains(&hr) && (0..60).contains(&mn) && (0..60).contains(&sc)) {
+        return Err(lst2et_error(
+            "SPICE(INVALIDTIME)",
+            format!(
+                "The local time {:02}:{:02}:{:02} is not a time of day.",
+                hr, mn, sc
+            ),
+        ));
+    }
+
+    // The rotation rate is given in degrees per day of 86400 seconds, a step of half a rotation
+    // or more would skip whole local days
+    let (rate, _) = raw::gdpool(&format!("BODY{}_PM", body), 1, 1);
+    if let Some(rate) = rate.first().filter(|rate| **rate != 0.0) {
+        let half_rotation = 180.0 / rate.abs() * 86400.0;
+        if step >= half_rotation {
+            return Err(lst2et_error(
+                "SPICE(INVALIDSTEP)",
+                format!(
+                    "The step {} must be shorter than half the rotation period of the body {}, {} \
+                     seconds.",
+                    step, body, half_rotation
+                ),
+            ));
+        }
+    }
+
+    // Once an error is signaled, `et2lst` returns 00:00:00 without advancing, so every sample
+    // must be checked to not search forever
+    let seconds = |et: f64| {
+        try_call(|| et2lst(et, body, lon, lon_type))
+            .map(|(hr, mn, sc, _, _)| f64::from(hr * 3600 + mn * 60 + sc))
+    };
+    let elapsed = |from: f64, to: f64| -> error::Result<f64> {
+        Ok((seconds(to)? - seconds(from)?).rem_euclid(SECONDS_PER_DAY))
+    };
+
+    let after = Into::<Et>::into(after).0;
+    let target = f64::from(hr * 3600 + mn * 60 + sc);
+    let mut remaining = (target - seconds(after)?).rem_euclid(SECONDS_PER_DAY);
+    if remaining == 0.0 {
+        return Ok(Et(after));
+    }
+
+    let mut start = after;
+    let mut stop = start + step;
+    for steps in 1.. {
+        let advance = elapsed(start, stop)?;
+        if advance >= remaining {
+            break;
+        }
+        if steps == LST_MAX_STEPS {
+            return Err(lst2et_error(
+                "SPICE(NOCONVERGENCE)",
+                format!(
+                    "The local time {:02}:{:02}:{:02} is not reached within {} steps of {} \
+                     seconds.",
+                    hr, mn, sc, LST_MAX_STEPS, step
+                ),
+            ));
+        }
+        remaining -= advance;
+        start = stop;
+        stop += step;
+    }
+
+    while stop - start > LST_TOLERANCE {
+        let middle = 0.5 * (start + stop);
+        let advance = elapsed(start, middle)?;
+        if advance < remaining {
+            remaining -= advance;
+            start = middle;
+        } else {
+            stop = middle;
+        }
+    }
+    Ok(Et(stop))
+}
+
+/**
+Error of the arguments given to [`lst2et`].
+*/
+fn lst2et_error(short: &str, long: String) -> SpiceError {
+    SpiceError {
+        short: short.to_string(),
+        long,
+        traceback: "lst2et".to_string(),
+    }
+}
+
+/**
+Convert an input time from ephemeris seconds past J2000 to Calendar, Day-of-Year, or Julian Date
+format, UTC.
+
+See [`raw::et2utc`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn et2utc(et: impl Into<Et>, format: &str, prec: i32) -> String {
+    raw::et2utc(et, format, prec, MAX_LEN_OUT)
+}
+
+/**
+Convert from an ephemeris epoch measured in seconds past the epoch of J2000 to a calendar string
+format using a formal calendar free of leapseconds.
+
+See [`raw::etcal`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn etcal(et: impl Into<Et>) -> String {
+    raw::etcal(et, MAX_LEN_OUT)
+}
+
+/**
+Set and retrieve the defaults associated with calendar input strings.
+
+See [`raw::timdef`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn timdef(action: &str, item: &str, value: &str) -> String {
+    raw::timdef(action, item, value, MAX_LEN_OUT)
+}
+
+/**
+Parse a time string and return seconds past the J2000 epoch on a formal calendar, or the
+diagnostic message explaining why the string could not be parsed.
+
+See [`raw::tparse`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn tparse(string: &str) -> Result<f64, String> {
+    match raw::tparse(string, MAX_LEN_OUT) {
+        (sp2000, errmsg) if errmsg.is_empty() => Ok(sp2000),
+        (_, errmsg) => Err(errmsg),
+    }
+}
+
+/**
+Create a time format picture suitable for use by the routine [`timout`] from a given sample time
+string, or the diagnostic message explaining why the sample could not be understood.
+
+See [`raw::tpictr`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn tpictr(sample: &str) -> Result<String, String> {
+    match raw::tpictr(sample, MAX_LEN_OUT, MAX_LEN_OUT) {
+        (pictur, true, _) => Ok(pictur),
+        (_, false, error) => Err(error),
+    }
+}
+
+/**
+Convert double precision encoding of spacecraft clock time into a character representation.
+
+See [`raw::scdecd`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn scdecd(sc: i32, sclkdp: f64) -> String {
+    raw::scdecd(sc, sclkdp, MAX_LEN_OUT)
+}
+
+/**
+Convert an epoch specified as ephemeris seconds past J2000 (ET) to a character string
+representation of a spacecraft clock value (SCLK).
+
+See [`raw::sce2s`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn sce2s(sc: i32, et: impl Into<Et>) -> String {
+    raw::sce2s(sc, et, MAX_LEN_OUT)
+}
+
+/**
+Transform a state, such as returned by [`raw::spkezr`], from one frame to another at a specified
+epoch.
+
+See [`raw::sxform`] for the state transformation matrix.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn sxform_state(state: [f64; 6], from: &str, to: &str, et: impl Into<Et>) -> [f64; 6] {
+    let xform = raw::sxform(from, to, et);
+    let mut transformed = [0.0; 6];
+    for (output, row) in transformed.iter_mut().zip(xform.iter()) {
+        *output = row.iter().zip(state.iter()).map(|(x, s)| x * s).sum();
+    }
+    transformed
+}
+
 /**
 Fetch triangular plates from a type 2 DSK segment.
 
@@ -76,3 +410,93 @@ pub fn kdata(which: i32, kind: &str) -> (String, String, String, i32, bool) {
         MAX_LEN_OUT as i32,
     )
 }
+
+/**
+Return information about a loaded SPICE kernel specified by name.
+
+See [`raw::kinfo`] for the raw interface.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn kinfo(file: &str) -> (String, String, i32, bool) {
+    raw::kinfo(file, MAX_LEN_OUT as i32, MAX_LEN_OUT as i32)
+}
+
+/**
+Paths of the loaded kernels of a given kind.
+*/
+fn loaded_paths(kind: KernelKind) -> Vec<String> {
+    loaded_kernels_unlocked(&[kind])
+        .map(|kernel| kernel.path)
+        .collect()
+}
+
+/**
+Find the coverage window for a specified object over all the loaded CK files.
+
+See [`raw::ckcov`] for the coverage in a single file.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn ckcov_loaded(idcode: i32, needav: bool, level: &str, tol: f64, timsys: &str) -> Window {
+    loaded_paths(KernelKind::Ck)
+        .iter()
+        .fold(Window::new(0), |cover, file| {
+            cover.union_unlocked(&raw::ckcov(file, idcode, needav, level, tol, timsys))
+        })
+}
+
+/**
+Find the set of ID codes of all objects in the loaded CK files.
+
+See [`raw::ckobj`] for the objects of a single file.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn ckobj_loaded() -> Cell<i32> {
+    loaded_paths(KernelKind::Ck)
+        .iter()
+        .fold(Cell::<i32>::new(0), |ids, file| {
+            ids.union_unlocked(&raw::ckobj(file))
+        })
+}
+
+/**
+Find the coverage window for a specified reference frame class ID over all the loaded binary PCK
+files.
+
+See [`raw::pckcov`] for the coverage in a single file.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn pckcov_loaded(idcode: i32) -> Window {
+    loaded_paths(KernelKind::Pck)
+        .iter()
+        .fold(Window::new(0), |cover, file| {
+            cover.union_unlocked(&raw::pckcov(file, idcode))
+        })
+}
+
+/**
+Find the coverage window for a specified ephemeris object over all the loaded SPK files.
+
+See [`raw::spkcov`] for the coverage in a single file.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkcov_loaded(idcode: i32) -> Window {
+    loaded_paths(KernelKind::Spk)
+        .iter()
+        .fold(Window::new(0), |cover, file| {
+            cover.union_unlocked(&raw::spkcov(file, idcode))
+        })
+}
+
+/**
+Find the set of ID codes of all objects in the loaded SPK files.
+
+See [`raw::spkobj`] for the objects of a single file.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn spkobj_loaded() -> Cell<i32> {
+    loaded_paths(KernelKind::Spk)
+        .iter()
+        .fold(Cell::<i32>::new(0), |ids, file| {
+            ids.union_unlocked(&raw::spkobj(file))
+        })
+}
@@ -0,0 +1,257 @@
+/*!
+Typed access to the variables of the kernel pool.
+
+## Description
+
+The kernel pool holds the variables assigned by the text kernels loaded, or inserted at runtime.
+A variable is either numeric or character, and holds one or more values. The readers of this module
+return [`None`] when the variable is not in the pool or is not of the requested type, so a missing
+variable cannot be mistaken for an empty one. The writers return a [`Result`] carrying the error
+signaled by CSPICE, if any.
+
+A [`PoolWatcher`] tells whether some variables were updated since it was last polled, for instance
+to invalidate values cached from the pool when a new text kernel is loaded.
+
+With the `lock` feature, the readers, the writers, [`PoolWatcher::new`], [`PoolWatcher::poll`]
+and [`PoolWatcher::changes`] take the [`SpiceLock`][crate::SpiceLock] as an additional argument.
+The iterator of changes keeps it borrowed, as it polls the pool while iterating.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/kernel.html#Kernel%20Pool).
+*/
+
+use crate::core::error::{try_call, Result};
+use crate::core::LockRef;
+use crate::{raw, MAX_LEN_OUT};
+use spice_derive::with_lock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/**
+Number of names fetched at once by [`names`].
+*/
+const NAMES_ROOM: usize = 100;
+
+/**
+Number of watchers created, used to give each its own agent.
+*/
+static WATCHERS: AtomicUsize = AtomicUsize::new(0);
+
+/**
+Type of the values of a kernel pool variable.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariableType {
+    /// Double precision values, also readable as integers.
+    Numeric,
+    /// String values.
+    Character,
+}
+
+/**
+Type and number of values of a variable, or [`None`] if it is not in the pool.
+
+See [dtpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/dtpool_c.html).
+*/
+#[with_lock(unlocked)]
+pub fn describe(name: &str) -> Option<(VariableType, usize)> {
+    match raw::dtpool(name) {
+        (n, 'N', true) => Some((VariableType::Numeric, n as usize)),
+        (n, 'C', true) => Some((VariableType::Character, n as usize)),
+        _ => None,
+    }
+}
+
+/**
+Number of values of a variable of the given type, or [`None`] if there is no such variable.
+*/
+fn size(name: &str, vtype: VariableType) -> Option<usize> {
+    match describe_unlocked(name)? {
+        (found, n) if found == vtype => Some(n),
+        _ => None,
+    }
+}
+
+/**
+Values of a numeric variable.
+
+See [gdpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gdpool_c.html).
+*/
+#[with_lock]
+pub fn get_f64s(name: &str) -> Option<Vec<f64>> {
+    let n = size(name, VariableType::Numeric)?;
+    Some(raw::gdpool(name, 0, n).0)
+}
+
+/**
+Values of a numeric variable, rounded to the nearest integers.
+
+See [gipool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gipool_c.html).
+*/
+#[with_lock]
+pub fn get_i32s(name: &str) -> Option<Vec<i32>> {
+    let n = size(name, VariableType::Numeric)?;
+    Some(raw::gipool(name, 0, n).0)
+}
+
+/**
+Values of a character variable.
+
+See [gcpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gcpool_c.html).
+*/
+#[with_lock]
+pub fn get_strings(name: &str) -> Option<Vec<String>> {
+    let n = size(name, VariableType::Character)?;
+    Some(raw::gcpool(name, 0, n, MAX_LEN_OUT).0)
+}
+
+/**
+Names of the variables matching a template, where `*` matches any substring and `%` matches any
+character.
+
+See [gnpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/gnpool_c.html).
+*/
+#[with_lock]
+pub fn names(template: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    loop {
+        let (page, found) = raw::gnpool(template, names.len(), NAMES_ROOM, MAX_LEN_OUT);
+        let done = !found || page.len() < NAMES_ROOM;
+        names.extend(page);
+        if done {
+            return names;
+        }
+    }
+}
+
+/**
+Insert numeric values into the pool, replacing the variable if it exists.
+
+See [pdpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pdpool_c.html).
+*/
+#[with_lock]
+pub fn put_f64s(name: &str, values: &[f64]) -> Result<()> {
+    try_call(|| raw::pdpool(name, values))
+}
+
+/**
+Insert integer values into the pool, replacing the variable if it exists.
+
+See [pipool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pipool_c.html).
+*/
+#[with_lock]
+pub fn put_i32s(name: &str, values: &[i32]) -> Result<()> {
+    try_call(|| raw::pipool(name, values))
+}
+
+/**
+Insert string values into the pool, replacing the variable if it exists.
+
+See [pcpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/pcpool_c.html).
+*/
+#[with_lock]
+pub fn put_strings(name: &str, values: &[&str]) -> Result<()> {
+    try_call(|| raw::pcpool(name, values))
+}
+
+/**
+A watch on a set of variables, polled for updates.
+
+Each watcher registers its own agent in the kernel pool. An update is reported once per poll,
+whatever the number of watched variables updated or the number of times they were updated since
+the previous poll. The first poll always reports an update, so that values can be read from the
+pool the first time.
+
+CSPICE cannot delete a watch, so the agent stays registered after the watcher is dropped.
+
+See [swpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/swpool_c.html).
+*/
+#[derive(Debug)]
+pub struct PoolWatcher {
+    agent: String,
+    names: Vec<String>,
+}
+
+impl PoolWatcher {
+    /**
+    Watch the variables of the given names, which do not need to be in the pool yet.
+    */
+    #[with_lock]
+    pub fn new(names: &[&str]) -> Result<Self> {
+        let agent = format!(
+            "RUST_SPICE_WATCHER_{}",
+            WATCHERS.fetch_add(1, Ordering::Relaxed)
+        );
+        try_call(|| raw::swpool(&agent, names))?;
+        Ok(Self {
+            agent,
+            names: names.iter().map(|name| name.to_string()).collect(),
+        })
+    }
+
+    /**
+    Name of the agent notified of the updates.
+    */
+    pub fn agent(&self) -> &str {
+        &self.agent
+    }
+
+    /**
+    Names of the watched variables.
+    */
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /**
+    Whether any of the watched variables was updated since the previous poll.
+
+    See [cvpool_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/cvpool_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn poll(&self) -> bool {
+        raw::cvpool(&self.agent)
+    }
+
+    /**
+    Iterate over the updates not yet polled: the iterator yields at most once, and ends once the
+    watcher is up to date.
+    */
+    #[with_lock(lock: &'l crate::SpiceLock)]
+    pub fn changes<'l>(&self) -> Changes<'_, 'l> {
+        Changes {
+            watcher: self,
+            _lock: lock_ref!(lock),
+        }
+    }
+}
+
+/**
+Notification that some of the watched variables of a [`PoolWatcher`] were updated.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolChange<'a> {
+    /// Names of the watched variables, any of which may have been updated.
+    pub names: &'a [String],
+}
+
+/**
+Iterator over the updates of a [`PoolWatcher`], returned by [`PoolWatcher::changes`].
+*/
+#[derive(Debug)]
+pub struct Changes<'a, 'l> {
+    watcher: &'a PoolWatcher,
+    _lock: LockRef<'l>,
+}
+
+impl<'a> Iterator for Changes<'a, '_> {
+    type Item = PoolChange<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.watcher.poll_unlocked() {
+            Some(PoolChange {
+                names: &self.watcher.names,
+            })
+        } else {
+            None
+        }
+    }
+}
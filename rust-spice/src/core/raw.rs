@@ -2,9 +2,16 @@
 A Rust idiomatic CSPICE wrapper built with [procedural macros][`spice_derive`].
 */
 
-use crate::{c, cstr, fcstr, malloc, mallocstr};
-use spice_derive::{cspice_proc, return_output};
-use std::ops::{Deref, DerefMut};
+use crate::core::cell::fill;
+use crate::core::time::Et;
+use crate::core::window::Window;
+use crate::{c, cstr, fcstr, mallocstr, mptr};
+use spice_derive::{cspice_proc, fallible, return_output};
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
 
 #[cfg(any(feature = "lock", doc))]
 use {crate::core::lock::SpiceLock, spice_derive::impl_for};
@@ -13,57 +20,35 @@ use {crate::core::lock::SpiceLock, spice_derive::impl_for};
 pub type DLADSC = c::SpiceDLADescr;
 #[allow(clippy::upper_case_acronyms)]
 pub type DSKDSC = c::SpiceDSKDescr;
+#[cfg(not(feature = "lock"))]
 #[allow(clippy::upper_case_acronyms)]
 pub type CELL = c::SpiceCell;
-pub const CELL_MAXID: usize = 10_000;
 
-/**
-A cell is a data structure intended to provide safe array access within the applications.
+pub use crate::core::cell::{Cell, CELL_MAXID};
 
-See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/cells.html).
+/**
+Maximum number of partitions of a spacecraft clock.
 */
-#[derive(Debug)]
-pub struct Cell(c::SpiceCell);
-
-impl Cell {
-    /**
-    Declare a cell from integer.
-    */
-    pub fn new_int() -> Self {
-        let base = malloc!(i32, CELL_MAXID + c::SPICE_CELL_CTRLSZ as usize);
-        Self(CELL {
-            dtype: c::_SpiceDataType_SPICE_INT,
-            length: 0i32,
-            size: CELL_MAXID as i32,
-            card: 0i32,
-            isSet: 1i32,
-            adjust: 0i32,
-            init: 0i32,
-            base: base as *mut libc::c_void,
-            data: base.wrapping_add(c::SPICE_CELL_CTRLSZ as usize) as *mut libc::c_void,
-        })
-    }
+const MXPART: usize = 9999;
 
+cspice_proc! {
     /**
-    Declare data from a cell at index.
+    Construct the matrix that rotates vectors by a specified angle, in radians, about a
+    specified axis, counterclockwise when looking from the tip of the axis.
     */
-    pub fn get_data_int(&self, index: usize) -> i32 {
-        unsafe { *(self.data as *mut i32).wrapping_add(index) }
-    }
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn axisar(axis: [f64; 3], angle: f64) -> [[f64; 3]; 3] {}
 }
 
-impl Deref for Cell {
-    type Target = c::SpiceCell;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
+cspice_proc! {
+    /**
+    Convert from range, azimuth and elevation of a point to rectangular coordinates.
 
-impl DerefMut for Cell {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
+    `azccw` tells whether the azimuth increases counterclockwise, and `elplsz` whether the elevation
+    increases toward +Z.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn azlrec(range: f64, az: f64, el: f64, azccw: bool, elplsz: bool) -> [f64; 3] {}
 }
 
 cspice_proc! {
@@ -72,6 +57,7 @@ cspice_proc! {
 
     This function has a [neat version][crate::neat::bodc2n].
     */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_bodc2n))]
     pub fn bodc2n(code: i32, lenout: i32) -> (String, bool) {}
 }
 
@@ -79,6 +65,7 @@ cspice_proc! {
     /**
     Determine whether values exist for some item for any body in the kernel pool.
     */
+    #[fallible]
     #[return_output]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn bodfnd(body: i32, item: &str) -> bool {}
@@ -88,6 +75,7 @@ cspice_proc! {
     /**
     Translate the name of a body or object to the corresponding SPICE integer ID code.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn bodn2c(name: &str) -> (i32, bool) {}
 }
@@ -95,20 +83,126 @@ cspice_proc! {
 /**
 Fetch from the kernel pool the double precision values of an item associated with a body.
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn bodvrd(bodynm: &str, item: &str, maxn: usize) -> Vec<f64> {
-    let bodynm = cstr!(bodynm);
-    let item = cstr!(item);
+    let mut bodynm = cstr!(bodynm);
+    let mut item = cstr!(item);
     let mut dim = 0;
     let mut values = vec![0.0; maxn];
-    unsafe { crate::c::bodvrd_c(bodynm, item, maxn as _, &mut dim, values.as_mut_ptr()) };
+    unsafe {
+        crate::c::bodvrd_c(
+            mptr!(bodynm),
+            mptr!(item),
+            maxn as _,
+            &mut dim,
+            values.as_mut_ptr(),
+        )
+    };
     values.truncate(dim as _);
     values
 }
 
+cspice_proc! {
+    /**
+    Return the frame name, frame ID, and center associated with a given frame class and class ID.
+
+    This function has a [neat version][crate::neat::ccifrm].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_ccifrm))]
+    pub fn ccifrm(frclss: i32, clssid: i32, lenout: i32) -> (i32, String, i32, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Retrieve frame ID code and name to associate with a frame center.
+
+    This function has a [neat version][crate::neat::cidfrm].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_cidfrm))]
+    pub fn cidfrm(cent: i32, lenout: i32) -> (i32, String, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Find the coverage window for a specified object in a specified CK file.
+
+    `level` is either `"SEGMENT"` or `"INTERVAL"`, `tol` is the tolerance in ticks used to expand
+    the intervals found, and `timsys` is either `"SCLK"` or `"TDB"` for the time system of the
+    returned window. If `needav` is true, only the data for which angular velocity is available is
+    considered.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn ckcov(ck: &str, idcode: i32, needav: bool, level: &str, tol: f64, timsys: &str) -> Window {}
+}
+
+cspice_proc! {
+    /**
+    Get pointing (attitude) for a specified spacecraft clock time.
+
+    This function has a [neat version][crate::neat::ckgp].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_ckgp))]
+    pub fn ckgp(inst: i32, sclkdp: f64, tol: f64, ref_: &str) -> ([[f64; 3]; 3], f64, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Get pointing (attitude) and angular velocity for a specified spacecraft clock time.
+
+    This function has a [neat version][crate::neat::ckgpav].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_ckgpav))]
+    pub fn ckgpav(
+        inst: i32,
+        sclkdp: f64,
+        tol: f64,
+        ref_: &str,
+    ) -> ([[f64; 3]; 3], [f64; 3], f64, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Find the set of ID codes of all objects in a specified CK file.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn ckobj(ck: &str) -> Cell<i32> {}
+}
+
+cspice_proc! {
+    /**
+    Retrieve frame ID code and name to associate with an object.
+
+    This function has a [neat version][crate::neat::cnmfrm].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_cnmfrm))]
+    pub fn cnmfrm(cname: &str, lenout: i32) -> (i32, String, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Indicate whether or not any watched kernel variables that have a specified agent on their
+    notification list have been updated.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn cvpool(agent: &str) -> bool {}
+}
+
+cspice_proc! {
+    /**
+    Convert from cylindrical to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn cylrec(r: f64, clon: f64, z: f64) -> [f64; 3] {}
+}
+
 cspice_proc! {
     /**
     close a das file.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dascls(handle: i32) {}
 }
@@ -117,34 +211,137 @@ cspice_proc! {
     /**
     Open a DAS file for reading.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dasopr(fname: &str) -> i32 {}
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from rectangular to azimuth/elevation
+    coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dazldr(x: f64, y: f64, z: f64, azccw: bool, elplsz: bool) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from rectangular to cylindrical coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dcyldr(x: f64, y: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
 /**
 Return the value of Delta ET (ET-UTC) for an input epoch.
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn deltet(epoch: f64, eptype: &str) -> f64 {
-    let eptype = cstr!(eptype);
+    let mut eptype = cstr!(eptype);
     let mut delta = 0.0;
     unsafe {
-        crate::c::deltet_c(epoch, eptype, &mut delta);
+        crate::c::deltet_c(epoch, mptr!(eptype), &mut delta);
     }
     delta
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from rectangular to geodetic coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dgeodr(x: f64, y: f64, z: f64, re: f64, f: f64) -> [[f64; 3]; 3] {}
+}
+
 cspice_proc! {
     /**
     Begin a forward segment search in a DLA file.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dlabfs(handle: i32) -> (DLADSC, bool) {}
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from rectangular to latitudinal coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dlatdr(x: f64, y: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from rectangular to planetographic
+    coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dpgrdr(body: &str, x: f64, y: f64, z: f64, re: f64, f: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from azimuth/elevation to rectangular
+    coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdazl(range: f64, az: f64, el: f64, azccw: bool, elplsz: bool) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from cylindrical to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdcyl(r: f64, clon: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from geodetic to rectangular coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdgeo(lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from latitudinal to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdlat(r: f64, lon: f64, lat: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from planetographic to rectangular
+    coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdpgr(body: &str, lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from spherical to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn drdsph(r: f64, colat: f64, slon: f64) -> [[f64; 3]; 3] {}
+}
+
 cspice_proc! {
     /**
     Return the DSK descriptor from a DSK segment identified  by a DAS handle and DLA descriptor.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dskgd(handle: i32, dladsc: DLADSC) -> DSKDSC {}
 }
@@ -153,6 +350,7 @@ cspice_proc! {
     /**
     Compute the unit normal vector for a specified plate from a type 2 DSK segment.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dskn02(handle: i32, dladsc: DLADSC, plid: i32) -> [f64; 3] {}
 }
@@ -162,8 +360,9 @@ cspice_proc! {
     Find the set of body ID codes of all objects for which topographic data are provided in a
     specified DSK file.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn dskobj(dsk: &str) -> Cell {}
+    pub fn dskobj(dsk: &str) -> Cell<i32> {}
 }
 
 /**
@@ -171,6 +370,7 @@ Fetch triangular plates from a type 2 DSK segment.
 
 This function has a [neat version][crate::neat::dskp02].
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_dskp02))]
 pub fn dskp02(handle: i32, mut dladsc: DLADSC, start: usize, room: usize) -> Vec<[i32; 3]> {
     let mut n = 0;
     let mut plates = vec![[0; 3]; room];
@@ -195,6 +395,7 @@ Fetch vertices from a type 2 DSK segment.
 
 This function has a [neat version][crate::neat::dskv02].
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_dskv02))]
 pub fn dskv02(handle: i32, mut dladsc: DLADSC, start: usize, room: usize) -> Vec<[f64; 3]> {
     let mut n = 0;
     let mut vrtces = vec![[0.0; 3]; room];
@@ -219,6 +420,7 @@ cspice_proc! {
     Determine the plate ID and body-fixed coordinates of the intersection of a specified ray with
     the surface defined by a type 2 DSK plate model.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dskx02(
         handle: i32,
@@ -239,22 +441,70 @@ cspice_proc! {
 
     See [`neat::dskp02`] for the raw interface.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn dskz02(handle: i32, dladsc: DLADSC) -> (i32, i32) {}
 }
 
+cspice_proc! {
+    /**
+    Compute the Jacobian matrix of the transformation from rectangular to spherical coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn dsphdr(x: f64, y: f64, z: f64) -> [[f64; 3]; 3] {}
+}
+
+/**
+Return the number of components and the type of a kernel pool variable: `'C'` for character or
+`'N'` for numeric.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn dtpool(name: &str) -> (i32, char, bool) {
+    let mut name = cstr!(name);
+    let mut found = 0;
+    let mut n = 0;
+    let mut vtype = mallocstr!(1);
+    unsafe { crate::c::dtpool_c(mptr!(name), &mut found, &mut n, mptr!(vtype)) };
+    (n, vtype[0] as u8 as char, found != 0)
+}
+
+/**
+Return the character value of a kernel variable from the kernel pool, with whether it was found.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn gcpool(name: &str, start: usize, room: usize, lenout: usize) -> (Vec<String>, bool) {
+    let mut name = cstr!(name);
+    let mut n = 0;
+    let mut values = crate::malloc!(std::os::raw::c_char, room * lenout);
+    let mut found = 0;
+    unsafe {
+        crate::c::gcpool_c(
+            mptr!(name),
+            start as _,
+            room as _,
+            lenout as _,
+            &mut n,
+            mptr!(values) as *mut c_void,
+            &mut found,
+        )
+    };
+    (split_strings(&values, lenout, n as _), found != 0)
+}
+
 /**
-Return the d.p. value of a kernel variable from the kernel pool.
+Return the d.p. value of a kernel variable from the kernel pool, with whether it was found.
 */
-pub fn gdpool(name: &str, start: usize, room: usize) -> Vec<f64> {
-    let name = cstr!(name);
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn gdpool(name: &str, start: usize, room: usize) -> (Vec<f64>, bool) {
+    let mut name = cstr!(name);
     let start = start as _;
     let mut n = 0;
     let mut values = vec![0.0; room];
     let mut found = 0;
     unsafe {
         crate::c::gdpool_c(
-            name,
+            mptr!(name),
             start,
             room as _,
             &mut n,
@@ -262,15 +512,15 @@ pub fn gdpool(name: &str, start: usize, room: usize) -> Vec<f64> {
             &mut found,
         )
     }
-    // let found = found != 0;
     values.truncate(n as _);
-    values
+    (values, found != 0)
 }
 
 cspice_proc! {
     /**
     Convert geodetic coordinates to rectangular coordinates.
      */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn georec(lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [f64; 3] {}
 }
@@ -279,14 +529,15 @@ cspice_proc! {
 Return the field-of-view (FOV) parameters for a specified
 instrument. The instrument is specified by its NAIF ID code.
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn getfov(
     instid: isize,
     room: usize,
     shapelen: usize,
     framelen: usize,
 ) -> (String, String, [f64; 3], Vec<[f64; 3]>) {
-    let shape = mallocstr!(shapelen);
-    let frame = mallocstr!(framelen);
+    let mut shape = mallocstr!(shapelen);
+    let mut frame = mallocstr!(framelen);
 
     let mut bsight = [0.0; 3];
     let mut n = 0;
@@ -298,8 +549,8 @@ pub fn getfov(
             room as _,
             shapelen as _,
             framelen as _,
-            shape,
-            frame,
+            mptr!(shape),
+            mptr!(frame),
             bsight.as_mut_ptr(),
             &mut n,
             bounds.as_mut_ptr(),
@@ -312,121 +563,774 @@ pub fn getfov(
 
 cspice_proc! {
     /**
-    Compute the illumination angles---phase, incidence, and emission---at a specified point on a
-    target body. Return logical flags indicating whether the surface point is visible from the
-    observer's position and whether the surface point is illuminated.
+    Return the time window over which a specified constraint on observer-target distance is met.
 
-    The target body's surface is represented using topographic data provided by DSK files, or by a
-    reference ellipsoid.
-
-    The illumination source is a specified ephemeris object.
+    `relate` is one of `">"`, `"="`, `"<"`, `"ABSMAX"`, `"ABSMIN"`, `"LOCMAX"` or `"LOCMIN"`,
+    `adjust` is only used for the absolute extrema searches, and `nintvls` is the number of
+    intervals of the workspace windows. The result window holds at least as many intervals, so
+    `nintvls` is the only limit on the size of the result: CSPICE signals SPICE(WINDOWEXCESS) when
+    the workspace windows are too small.
     */
+    #[fallible]
     #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn illumf(
-        method: &str,
+    pub fn gfdist(
         target: &str,
-        ilusrc: &str,
-        et: f64,
-        fixref: &str,
         abcorr: &str,
         obsrvr: &str,
-        spoint: [f64; 3]
-    ) -> (f64, [f64; 3], f64, f64, f64, bool, bool) {}
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
 }
 
 cspice_proc! {
     /**
-    Load one or more SPICE kernels into a program.
+    Return the time window over which a specified constraint on the observed phase, solar
+    incidence, or emission angle at a specified target body surface point is met.
+
+    `angtyp` is one of `"PHASE"`, `"INCIDENCE"` or `"EMISSION"`. See [`gfdist`] for the
+    constraint arguments.
     */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn furnsh(name: &str) {}
+    pub fn gfilum(
+        method: &str,
+        angtyp: &str,
+        target: &str,
+        illum: &str,
+        fixref: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        spoint: [f64; 3],
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
 }
 
 cspice_proc! {
     /**
-    Clear the KEEPER subsystem: unload all kernels, clear the kernel pool, and re-initialize the
-    subsystem. Existing watches on kernel variables are retained.
+    Determine time intervals when an observer sees one target occulted by, or in transit across,
+    another.
+
+    `occtyp` is one of `"FULL"`, `"ANNULAR"`, `"PARTIAL"` or `"ANY"`.
     */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn kclear() {}
-}
-
-/**
-Fetch vertices from a type 2 DSK segment.
-
-This function has a [neat version][crate::neat::kdata].
-*/
-pub fn kdata(
-    which: i32,
-    kind: &str,
-    fillen: i32,
-    typlen: i32,
-    srclen: i32,
-) -> (String, String, String, i32, bool) {
-    #[allow(unused_unsafe)]
-    unsafe {
-        let varout_0 = mallocstr!(fillen);
-        let varout_1 = mallocstr!(typlen);
-        let varout_2 = mallocstr!(srclen);
-        let mut varout_3 = 0i32;
-        let mut varout_4 = 0i32;
-        crate::c::kdata_c(
-            which,
-            cstr!(kind),
-            fillen,
-            typlen,
-            srclen,
-            varout_0,
-            varout_1,
-            varout_2,
-            &mut varout_3,
-            &mut varout_4,
-        );
-        (
-            fcstr!(varout_0),
-            fcstr!(varout_1),
-            fcstr!(varout_2),
-            varout_3,
-            varout_4 != 0,
-        )
-    }
+    pub fn gfoclt(
+        occtyp: &str,
+        front: &str,
+        fshape: &str,
+        fframe: &str,
+        back: &str,
+        bshape: &str,
+        bframe: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        step: f64,
+        cnfine: &Window
+    ) -> Window {}
 }
 
 cspice_proc! {
     /**
-    Return the current number of kernels that have been loaded via the KEEPER interface that are of
-    a specified type.
+    Determine time intervals for which a specified constraint on the phase angle between an
+    illumination source, a target, and observer body centers is met.
+
+    See [`gfdist`] for the constraint arguments.
     */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn ktotal(kind: &str) -> i32 {}
+    pub fn gfpa(
+        target: &str,
+        illum: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
 }
 
 cspice_proc! {
     /**
-    Convert from latitudinal coordinates to rectangular coordinates.
+    Determine time intervals for which a coordinate of an observer-target position vector satisfies
+    a numerical constraint.
+
+    `crdsys` is a coordinate system such as `"RECTANGULAR"` or `"LATITUDINAL"` and `coord` one of
+    its coordinates such as `"X"` or `"LATITUDE"`. See [`gfdist`] for the constraint arguments.
     */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn latrec(radius: f64, longitude: f64, latitude: f64) -> [f64; 3] {}
+    pub fn gfposc(
+        target: &str,
+        frame: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        crdsys: &str,
+        coord: &str,
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
 }
 
 cspice_proc! {
     /**
-       Multiply a 3x3 double precision matrix with a 3-dimensional double precision vector.
+    Determine time intervals when a specified ray intersects the space bounded by the field-of-view
+    (FOV) of a specified instrument.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn mxv(m1: [[f64; 3]; 3], vin: [f64; 3]) -> [f64; 3] {}
+    pub fn gfrfov(
+        inst: &str,
+        raydir: [f64; 3],
+        rframe: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        step: f64,
+        cnfine: &Window
+    ) -> Window {}
 }
 
 cspice_proc! {
     /**
-    Determines the occultation condition (not occulted, partially, etc.) of one target relative to
-    another target as seen by an observer at a given time, with targets modeled as points,
-    ellipsoids, or digital shapes (DSK)
+    Determine time intervals for which a specified constraint on the observer-target range rate is
+    met.
+
+    See [`gfdist`] for the constraint arguments.
     */
+    #[fallible]
     #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn occult(
+    pub fn gfrr(
+        target: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
+}
+
+cspice_proc! {
+    /**
+    Determine time intervals when the angular separation between the position vectors of two
+    target bodies relative to an observer satisfies a numerical relationship.
+
+    `shape1` and `shape2` are either `"POINT"` or `"SPHERE"`. See [`gfdist`] for the constraint
+    arguments.
+    */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn gfsep(
+        targ1: &str,
+        shape1: &str,
+        frame1: &str,
+        targ2: &str,
+        shape2: &str,
+        frame2: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
+}
+
+cspice_proc! {
+    /**
+    Determine time intervals for which a coordinate of a surface intercept position vector
+    satisfies a numerical constraint.
+
+    See [`gfposc`] for the coordinate arguments and [`gfdist`] for the constraint arguments.
+    */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn gfsntc(
+        target: &str,
+        fixref: &str,
+        method: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        dref: &str,
+        dvec: [f64; 3],
+        crdsys: &str,
+        coord: &str,
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
+}
+
+cspice_proc! {
+    /**
+    Determine time intervals for which a coordinate of a subpoint position vector satisfies a
+    numerical constraint.
+
+    See [`gfposc`] for the coordinate arguments and [`gfdist`] for the constraint arguments.
+    */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn gfsubc(
+        target: &str,
+        fixref: &str,
+        method: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        crdsys: &str,
+        coord: &str,
+        relate: &str,
+        refval: f64,
+        adjust: f64,
+        step: f64,
+        nintvls: i32,
+        cnfine: &Window
+    ) -> Window {}
+}
+
+cspice_proc! {
+    /**
+    Determine time intervals when a specified ephemeris object intersects the space bounded by the
+    field-of-view (FOV) of a specified instrument.
+
+    `tshape` is either `"POINT"` or `"ELLIPSOID"`.
+    */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn gftfov(
+        inst: &str,
+        target: &str,
+        tshape: &str,
+        tframe: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        step: f64,
+        cnfine: &Window
+    ) -> Window {}
+}
+
+/**
+Perform a GF search on a user defined boolean quantity, returning the window where it is true.
+
+The closure `udfunb` tells whether the quantity is true at the given epoch. A panic in the closure
+stops calling it, and is resumed once CSPICE returns.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn gfudb<B: Fn(f64) -> bool>(udfunb: B, step: f64, cnfine: &Window) -> Window {
+    let mut cnfine = cnfine.clone();
+    let mut result = Window::new(CELL_MAXID);
+    fill(&mut result, |result| {
+        let functions = GfUserFunctions {
+            scalar: &|_| 0.0,
+            boolean: &udfunb,
+            panic: RefCell::new(None),
+        };
+        functions.search(|| unsafe {
+            c::gfudb_c(
+                Some(gf_user_scalar),
+                Some(gf_user_boolean),
+                step,
+                cnfine.as_mut_ptr(),
+                result.as_mut_ptr(),
+            )
+        })
+    });
+    result
+}
+
+/**
+Perform a GF search on a user defined scalar quantity, returning the window where it satisfies
+a constraint.
+
+The closure `udfuns` computes the quantity at the given epoch, and `decreasing` tells whether it is
+decreasing at the given epoch, the sign of its derivative. See [`gfdist`] for the constraint
+arguments. A panic in the closures stops calling them, and is resumed once CSPICE returns.
+*/
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn gfuds<S: Fn(f64) -> f64, D: Fn(f64) -> bool>(
+    udfuns: S,
+    decreasing: D,
+    relate: &str,
+    refval: f64,
+    adjust: f64,
+    step: f64,
+    nintvls: i32,
+    cnfine: &Window,
+) -> Window {
+    let mut relate = cstr!(relate);
+    let mut cnfine = cnfine.clone();
+    let mut result = Window::new(CELL_MAXID.max(nintvls.max(0) as usize));
+    fill(&mut result, |result| {
+        let functions = GfUserFunctions {
+            scalar: &udfuns,
+            boolean: &decreasing,
+            panic: RefCell::new(None),
+        };
+        functions.search(|| unsafe {
+            c::gfuds_c(
+                Some(gf_user_scalar),
+                Some(gf_user_boolean),
+                mptr!(relate),
+                refval,
+                adjust,
+                step,
+                nintvls,
+                cnfine.as_mut_ptr(),
+                result.as_mut_ptr(),
+            )
+        })
+    });
+    result
+}
+
+/**
+Closures of a user defined GF search, called by CSPICE through [`gf_user_scalar`] and
+[`gf_user_boolean`].
+*/
+struct GfUserFunctions<'a> {
+    scalar: &'a dyn Fn(f64) -> f64,
+    boolean: &'a dyn Fn(f64) -> bool,
+    // Payload of the first panic of the closures, if any.
+    panic: RefCell<Option<Box<dyn Any + Send>>>,
+}
+
+thread_local! {
+    // User defined functions of the innermost GF search running on this thread.
+    static GF_USER_FUNCTIONS: std::cell::Cell<*const c_void> =
+        const { std::cell::Cell::new(ptr::null()) };
+}
+
+impl<'a> GfUserFunctions<'a> {
+    /**
+    Run a search calling back these functions, and resume the panic of the closures if any.
+    */
+    fn search<F: FnOnce()>(self, search: F) {
+        let current = &self as *const Self as *const c_void;
+        let previous = GF_USER_FUNCTIONS.with(|functions| functions.replace(current));
+        search();
+        GF_USER_FUNCTIONS.with(|functions| functions.set(previous));
+        if let Some(payload) = self.panic.into_inner() {
+            panic::resume_unwind(payload)
+        }
+    }
+
+    /**
+    Call a closure of the search running on this thread, unless one has already panicked.
+    */
+    fn call<T, F: FnOnce(&GfUserFunctions) -> T>(call: F) -> Option<T> {
+        let current = GF_USER_FUNCTIONS.with(|functions| functions.get());
+        let functions = unsafe { &*(current as *const GfUserFunctions) };
+        if functions.panic.borrow().is_some() {
+            return None;
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| call(functions))) {
+            Ok(output) => Some(output),
+            Err(payload) => {
+                functions.panic.replace(Some(payload));
+                None
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn gf_user_scalar(et: f64, value: *mut f64) {
+    *value = GfUserFunctions::call(|functions| (functions.scalar)(et)).unwrap_or(0.0);
+}
+
+unsafe extern "C" fn gf_user_boolean(
+    _udfuns: Option<unsafe extern "C" fn(f64, *mut f64)>,
+    et: f64,
+    xbool: *mut i32,
+) {
+    *xbool = GfUserFunctions::call(|functions| (functions.boolean)(et)).unwrap_or(false) as i32;
+}
+
+/**
+Return the integer value of a kernel variable from the kernel pool, with whether it was found.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn gipool(name: &str, start: usize, room: usize) -> (Vec<i32>, bool) {
+    let mut name = cstr!(name);
+    let mut n = 0;
+    let mut values = vec![0; room];
+    let mut found = 0;
+    unsafe {
+        crate::c::gipool_c(
+            mptr!(name),
+            start as _,
+            room as _,
+            &mut n,
+            values.as_mut_ptr(),
+            &mut found,
+        )
+    };
+    values.truncate(n as _);
+    (values, found != 0)
+}
+
+/**
+Return names of kernel variables matching a specified template, with whether any was found.
+
+The template may contain the wildcards `*`, matching any substring, and `%`, matching any
+character.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn gnpool(name: &str, start: usize, room: usize, lenout: usize) -> (Vec<String>, bool) {
+    let mut name = cstr!(name);
+    let mut n = 0;
+    let mut kvars = crate::malloc!(std::os::raw::c_char, room * lenout);
+    let mut found = 0;
+    unsafe {
+        crate::c::gnpool_c(
+            mptr!(name),
+            start as _,
+            room as _,
+            lenout as _,
+            &mut n,
+            mptr!(kvars) as *mut c_void,
+            &mut found,
+        )
+    };
+    (split_strings(&kvars, lenout, n as _), found != 0)
+}
+
+cspice_proc! {
+    /**
+    Compute the illumination angles---phase, incidence, and emission---at a specified point on a
+    target body. Return logical flags indicating whether the surface point is visible from the
+    observer's position and whether the surface point is illuminated.
+
+    The target body's surface is represented using topographic data provided by DSK files, or by a
+    reference ellipsoid.
+
+    The illumination source is a specified ephemeris object.
+    */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn illumf(
+        method: &str,
+        target: &str,
+        ilusrc: &str,
+        et: impl Into<Et>,
+        fixref: &str,
+        abcorr: &str,
+        obsrvr: &str,
+        spoint: [f64; 3]
+    ) -> (f64, [f64; 3], f64, f64, f64, bool, bool) {}
+}
+
+/**
+Given an ephemeris epoch, compute the local solar time for an object on the surface of a body at a
+specified longitude, in radians.
+
+`lon_type` is either `"PLANETOCENTRIC"` or `"PLANETOGRAPHIC"`. The local time is returned as
+hours, minutes and seconds, and formatted as a 24-hour `"HR:MN:SC"` string and as a 12-hour
+`"HR:MN:SC AM"` string.
+
+This function has a [neat version][crate::neat::et2lst].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_et2lst))]
+pub fn et2lst(
+    et: impl Into<Et>,
+    body: i32,
+    lon: f64,
+    lon_type: &str,
+    timlen: usize,
+    ampmlen: usize,
+) -> (i32, i32, i32, String, String) {
+    let mut lon_type = cstr!(lon_type);
+    let mut hr = 0;
+    let mut mn = 0;
+    let mut sc = 0;
+    let mut time = mallocstr!(timlen);
+    let mut ampm = mallocstr!(ampmlen);
+    unsafe {
+        crate::c::et2lst_c(
+            Into::<Et>::into(et).0,
+            body,
+            lon,
+            mptr!(lon_type),
+            timlen as i32,
+            ampmlen as i32,
+            &mut hr,
+            &mut mn,
+            &mut sc,
+            mptr!(time),
+            mptr!(ampm),
+        )
+    };
+    (hr, mn, sc, fcstr!(time), fcstr!(ampm))
+}
+
+/**
+Convert an input time from ephemeris seconds past J2000 to Calendar, Day-of-Year, or Julian Date
+format, UTC.
+
+`format` is either `"C"` for calendar, `"D"` for day-of-year, `"J"` for Julian date, `"ISOC"` for
+ISO calendar or `"ISOD"` for ISO day-of-year, and `prec` is the number of decimal places of the
+seconds, or of the days for Julian dates.
+
+This function has a [neat version][crate::neat::et2utc].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_et2utc))]
+pub fn et2utc(et: impl Into<Et>, format: &str, prec: i32, lenout: usize) -> String {
+    let mut format = cstr!(format);
+    let mut utcstr = mallocstr!(lenout);
+    unsafe {
+        crate::c::et2utc_c(
+            Into::<Et>::into(et).0,
+            mptr!(format),
+            prec,
+            lenout as i32,
+            mptr!(utcstr),
+        )
+    };
+    fcstr!(utcstr)
+}
+
+/**
+Convert from an ephemeris epoch measured in seconds past the epoch of J2000 to a calendar string
+format using a formal calendar free of leapseconds.
+
+This function has a [neat version][crate::neat::etcal].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_etcal))]
+pub fn etcal(et: impl Into<Et>, lenout: usize) -> String {
+    let mut string = mallocstr!(lenout);
+    unsafe { crate::c::etcal_c(Into::<Et>::into(et).0, lenout as i32, mptr!(string)) };
+    fcstr!(string)
+}
+
+cspice_proc! {
+    /**
+    Construct a rotation matrix from a set of Euler angles, as the product
+    `[angle3]_axis3 [angle2]_axis2 [angle1]_axis1` where `[angle]_axis` is the matrix returned by
+    [`rotate`], rotating the frame about the axis of index 1, 2 or 3 (X, Y or Z).
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn eul2m(
+        angle3: f64,
+        angle2: f64,
+        angle1: f64,
+        axis3: i32,
+        axis2: i32,
+        axis1: i32,
+    ) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Retrieve the minimal attributes of a frame needed for computing transformations to or from
+    other frames: the center, the class and the class ID.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn frinfo(frcode: i32) -> (i32, i32, i32, bool) {}
+}
+
+cspice_proc! {
+    /**
+    Retrieve the name of a reference frame associated with a SPICE ID code, or an empty string if
+    there is none.
+
+    This function has a [neat version][crate::neat::frmnam].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_frmnam))]
+    pub fn frmnam(frcode: i32, lenout: i32) -> String {}
+}
+
+cspice_proc! {
+    /**
+    Load one or more SPICE kernels into a program.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn furnsh(name: &str) {}
+}
+
+cspice_proc! {
+    /**
+    Clear the KEEPER subsystem: unload all kernels, clear the kernel pool, and re-initialize the
+    subsystem. Existing watches on kernel variables are retained.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn kclear() {}
+}
+
+/**
+Return data for the nth kernel that is among a list of specified kernel types.
+
+This function has a [neat version][crate::neat::kdata].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_kdata))]
+pub fn kdata(
+    which: i32,
+    kind: &str,
+    fillen: i32,
+    typlen: i32,
+    srclen: i32,
+) -> (String, String, String, i32, bool) {
+    let mut kind = cstr!(kind);
+    #[allow(unused_unsafe)]
+    unsafe {
+        let mut varout_0 = mallocstr!(fillen);
+        let mut varout_1 = mallocstr!(typlen);
+        let mut varout_2 = mallocstr!(srclen);
+        let mut varout_3 = 0i32;
+        let mut varout_4 = 0i32;
+        crate::c::kdata_c(
+            which,
+            mptr!(kind),
+            fillen,
+            typlen,
+            srclen,
+            mptr!(varout_0),
+            mptr!(varout_1),
+            mptr!(varout_2),
+            &mut varout_3,
+            &mut varout_4,
+        );
+        (
+            fcstr!(varout_0),
+            fcstr!(varout_1),
+            fcstr!(varout_2),
+            varout_3,
+            varout_4 != 0,
+        )
+    }
+}
+
+/**
+Return information about a loaded SPICE kernel specified by name.
+
+This function has a [neat version][crate::neat::kinfo].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_kinfo))]
+pub fn kinfo(file: &str, typlen: i32, srclen: i32) -> (String, String, i32, bool) {
+    let mut file = cstr!(file);
+    #[allow(unused_unsafe)]
+    unsafe {
+        let mut varout_0 = mallocstr!(typlen);
+        let mut varout_1 = mallocstr!(srclen);
+        let mut varout_2 = 0i32;
+        let mut varout_3 = 0i32;
+        crate::c::kinfo_c(
+            mptr!(file),
+            typlen,
+            srclen,
+            mptr!(varout_0),
+            mptr!(varout_1),
+            &mut varout_2,
+            &mut varout_3,
+        );
+        (fcstr!(varout_0), fcstr!(varout_1), varout_2, varout_3 != 0)
+    }
+}
+
+cspice_proc! {
+    /**
+    Return the current number of kernels that have been loaded via the KEEPER interface that are of
+    a specified type.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn ktotal(kind: &str) -> i32 {}
+}
+
+cspice_proc! {
+    /**
+    Convert from latitudinal coordinates to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn latrec(radius: f64, longitude: f64, latitude: f64) -> [f64; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Factor a rotation matrix as a product of three rotations about specified coordinate axes,
+    returning `(angle3, angle2, angle1)` as defined by [`eul2m`].
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn m2eul(r: [[f64; 3]; 3], axis3: i32, axis2: i32, axis1: i32) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Find a unit quaternion corresponding to a specified rotation matrix.
+
+    SPICE quaternions put the scalar part first, `[cos(θ/2), sin(θ/2) a]` for the rotation of
+    vectors by the angle θ about the unit axis a, and are multiplied with the Hamilton convention.
+    This differs from the engineering convention, with the scalar part last and the rotation of the
+    frame instead of the vectors. The scalar part returned is not negative.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn m2q(r: [[f64; 3]; 3]) -> [f64; 4] {}
+}
+
+cspice_proc! {
+    /**
+       Multiply a 3x3 double precision matrix with a 3-dimensional double precision vector.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn mxv(m1: [[f64; 3]; 3], vin: [f64; 3]) -> [f64; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Look up the frame ID code associated with a string, or 0 if there is none.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn namfrm(frname: &str) -> i32 {}
+}
+
+cspice_proc! {
+    /**
+    Determines the occultation condition (not occulted, partially, etc.) of one target relative to
+    another target as seen by an observer at a given time, with targets modeled as points,
+    ellipsoids, or digital shapes (DSK)
+    */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn occult(
         targ1: &str,
         shape1: &str,
         frame1: &str,
@@ -435,17 +1339,75 @@ cspice_proc! {
         frame2: &str,
         abcorr: &str,
         obsrvr: &str,
-        et: f64,
+        et: impl Into<Et>,
     ) -> i32 {}
 }
 
+cspice_proc! {
+    /**
+    Find the coverage window for a specified reference frame class ID in a specified binary PCK
+    file.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn pckcov(pck: &str, idcode: i32) -> Window {}
+}
+
+/**
+Insert character data into the kernel pool.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn pcpool(name: &str, cvals: &[&str]) {
+    let mut name = cstr!(name);
+    let lenvals = cvals.iter().map(|value| value.len()).max().unwrap_or(0) + 1;
+    let cvals_ = join_strings(cvals, lenvals);
+    unsafe {
+        crate::c::pcpool_c(
+            mptr!(name),
+            cvals.len() as _,
+            lenvals as _,
+            cvals_.as_ptr() as *const c_void,
+        )
+    }
+}
+
+/**
+Insert double precision data into the kernel pool.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn pdpool(name: &str, dvals: &[f64]) {
+    let mut name = cstr!(name);
+    let mut dvals_ = dvals.to_vec();
+    unsafe { crate::c::pdpool_c(mptr!(name), dvals.len() as _, mptr!(dvals_)) }
+}
+
+cspice_proc! {
+    /**
+    Convert planetographic coordinates to rectangular coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn pgrrec(body: &str, lon: f64, lat: f64, alt: f64, re: f64, f: f64) -> [f64; 3] {}
+}
+
+/**
+Insert integer data into the kernel pool.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn pipool(name: &str, ivals: &[i32]) {
+    let mut name = cstr!(name);
+    let mut ivals_ = ivals.to_vec();
+    unsafe { crate::c::pipool_c(mptr!(name), ivals.len() as _, mptr!(ivals_)) }
+}
+
 cspice_proc! {
     /**
     Return the matrix that transforms position vectors from one specified frame to another at a
     specified epoch.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn pxform(from: &str, to: &str, et: f64) -> [[f64; 3]; 3] {}
+    pub fn pxform(from: &str, to: &str, et: impl Into<Et>) -> [[f64; 3]; 3] {}
 }
 
 cspice_proc! {
@@ -453,8 +1415,40 @@ cspice_proc! {
     Return the 3x3 matrix that transforms position vectors from one specified frame at a specified
     epoch to another specified frame at another specified epoch.
     */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn pxfrm2(
+        from: &str,
+        to: &str,
+        etfrom: impl Into<Et>,
+        etto: impl Into<Et>,
+    ) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Find the rotation matrix corresponding to a specified unit quaternion, in the convention of
+    [`m2q`].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn q2m(q: [f64; 4]) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Derive the angular velocity from a unit quaternion and its derivative with respect to time, in
+    the convention of [`m2q`].
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn qdq2av(q: [f64; 4], dq: [f64; 4]) -> [f64; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Multiply two quaternions, in the convention of [`m2q`].
+    */
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn pxfrm2(from: &str, to: &str, etfrom: f64, etto: f64) -> [[f64; 3]; 3] {}
+    pub fn qxq(q1: [f64; 4], q2: [f64; 4]) -> [f64; 4] {}
 }
 
 cspice_proc! {
@@ -465,16 +1459,82 @@ cspice_proc! {
     pub fn radrec(range: f64, ra: f64, dec: f64) -> [f64; 3] {}
 }
 
+cspice_proc! {
+    /**
+    Determine a state transformation matrix from a rotation matrix and the angular velocity of the
+    rotation.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn rav2xf(rot: [[f64; 3]; 3], av: [f64; 3]) -> [[f64; 6]; 6] {}
+}
+
+cspice_proc! {
+    /**
+    Compute the axis and the angle, in radians within `[0, π]`, of the rotation of vectors by a
+    rotation matrix, as defined by [`axisar`].
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn raxisa(matrix: [[f64; 3]; 3]) -> ([f64; 3], f64) {}
+}
+
+cspice_proc! {
+    /**
+    Convert rectangular coordinates of a point to range, azimuth and elevation.
+
+    `azccw` tells whether the azimuth increases counterclockwise, and `elplsz` whether the elevation
+    increases toward +Z.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn recazl(rectan: [f64; 3], azccw: bool, elplsz: bool) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Convert from rectangular to cylindrical coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn reccyl(rectan: [f64; 3]) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Convert from rectangular coordinates to geodetic coordinates.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn recgeo(rectan: [f64; 3], re: f64, f: f64) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Convert from rectangular coordinates to latitudinal coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn reclat(rectan: [f64; 3]) -> (f64, f64, f64) {}
+}
+
 /**
 Convert rectangular coordinates to planetographic coordinates.
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn recpgr(body: &str, rectan: [f64; 3], re: f64, f: f64) -> [f64; 3] {
-    let body = cstr!(body);
+    let mut body = cstr!(body);
     let mut rectan: [f64; 3] = rectan;
     let mut lon = 0.0;
     let mut lat = 0.0;
     let mut alt = 0.0;
-    unsafe { crate::c::recpgr_c(body, &mut rectan as _, re, f, &mut lon, &mut lat, &mut alt) };
+    unsafe {
+        crate::c::recpgr_c(
+            mptr!(body),
+            &mut rectan as _,
+            re,
+            f,
+            &mut lon,
+            &mut lat,
+            &mut alt,
+        )
+    };
     [lon, lat, alt]
 }
 
@@ -486,6 +1546,122 @@ cspice_proc! {
     pub fn recrad(rectan: [f64; 3]) -> (f64, f64, f64) {}
 }
 
+cspice_proc! {
+    /**
+    Convert from rectangular coordinates to spherical coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn recsph(rectan: [f64; 3]) -> (f64, f64, f64) {}
+}
+
+cspice_proc! {
+    /**
+    Calculate the 3x3 rotation matrix generated by a rotation of a specified angle, in radians,
+    about an axis of index 1, 2 or 3 (X, Y or Z).
+
+    The matrix rotates the coordinate frame, so it rotates vectors by the opposite angle.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn rotate(angle: f64, iaxis: i32) -> [[f64; 3]; 3] {}
+}
+
+cspice_proc! {
+    /**
+    Apply a rotation of an angle, in radians, about an axis of index 1, 2 or 3 to a matrix, as the
+    product of the matrix returned by [`rotate`] with the input matrix.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn rotmat(m1: [[f64; 3]; 3], angle: f64, iaxis: i32) -> [[f64; 3]; 3] {}
+}
+
+/**
+Convert double precision encoding of spacecraft clock time into a character representation.
+
+This function has a [neat version][crate::neat::scdecd].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_scdecd))]
+pub fn scdecd(sc: i32, sclkdp: f64, sclklen: usize) -> String {
+    let mut sclkch = mallocstr!(sclklen);
+    unsafe { crate::c::scdecd_c(sc, sclkdp, sclklen as i32, mptr!(sclkch)) };
+    fcstr!(sclkch)
+}
+
+cspice_proc! {
+    /**
+    Convert ephemeris seconds past J2000 (ET) to continuous encoded spacecraft clock ("ticks").
+    Non-integral tick values may be returned.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn sce2c(sc: i32, et: impl Into<Et>) -> f64 {}
+}
+
+/**
+Convert an epoch specified as ephemeris seconds past J2000 (ET) to a character string
+representation of a spacecraft clock value (SCLK).
+
+This function has a [neat version][crate::neat::sce2s].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_sce2s))]
+pub fn sce2s(sc: i32, et: impl Into<Et>, sclklen: usize) -> String {
+    let mut sclkch = mallocstr!(sclklen);
+    unsafe { crate::c::sce2s_c(sc, Into::<Et>::into(et).0, sclklen as i32, mptr!(sclkch)) };
+    fcstr!(sclkch)
+}
+
+cspice_proc! {
+    /**
+    Convert ephemeris seconds past J2000 (ET) to integral encoded spacecraft clock ("ticks").
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn sce2t(sc: i32, et: impl Into<Et>) -> f64 {}
+}
+
+cspice_proc! {
+    /**
+    Encode character representation of spacecraft clock time into a double precision number.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn scencd(sc: i32, sclkch: &str) -> f64 {}
+}
+
+/**
+Get spacecraft clock partition information from a spacecraft clock kernel file, as the start and
+stop times in ticks of each partition.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn scpart(sc: i32) -> Vec<(f64, f64)> {
+    let mut nparts = 0;
+    let mut pstart = vec![0.0; MXPART];
+    let mut pstop = vec![0.0; MXPART];
+    unsafe { crate::c::scpart_c(sc, &mut nparts, mptr!(pstart), mptr!(pstop)) };
+    pstart
+        .into_iter()
+        .zip(pstop)
+        .take(nparts.max(0) as usize)
+        .collect()
+}
+
+cspice_proc! {
+    /**
+    Convert a spacecraft clock string to ephemeris seconds past J2000 (ET).
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn scs2e(sc: i32, sclkch: &str) -> f64 {}
+}
+
+cspice_proc! {
+    /**
+    Convert encoded spacecraft clock ("ticks") to ephemeris seconds past J2000 (ET).
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn sct2e(sc: i32, sclkdp: f64) -> f64 {}
+}
+
 cspice_proc! {
     /**
     Compute, for a given observer and a ray emanating from the
@@ -498,30 +1674,60 @@ cspice_proc! {
 
     This routine supersedes srfxpt.
     */
+    #[fallible]
+    #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn sincpt(
         method:&str,
         target: &str,
-        et: f64,
-        fixred: &str,
+        et: impl Into<Et>,
+        fixref: &str,
         abcorr: &str,
         obsrvr: &str,
         dref: &str,
         dvec: [f64; 3]) -> ([f64; 3], f64, [f64; 3], bool) {}
 }
 
+cspice_proc! {
+    /**
+    Convert from spherical coordinates to rectangular coordinates.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn sphrec(r: f64, colat: f64, slon: f64) -> [f64; 3] {}
+}
+
 cspice_proc! {
     /**
     Close a SPK file opened for read or write.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn spkcls(handle: i32) {}
 }
 
+cspice_proc! {
+    /**
+    Find the coverage window for a specified ephemeris object in a specified SPK file.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn spkcov(spk: &str, idcode: i32) -> Window {}
+}
+
+cspice_proc! {
+    /**
+    Find the set of ID codes of all objects in a specified SPK file.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn spkobj(spk: &str) -> Cell<i32> {}
+}
+
 cspice_proc! {
     /**
     Create a new SPK file, returning the handle of the opened file
      */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn spkopn(fname: &str, ifname: &str, ncomch: i32) -> i32 {}
 }
@@ -530,6 +1736,7 @@ cspice_proc! {
     /**
     Write a type 9 segment to an SPK file.
     */
+    #[fallible]
     #[allow(clippy::too_many_arguments)]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn spkw09(handle: i32, body: i32, center: i32, frame: &str, first: f64, last: f64, segid: &str, degree: i32, n: i32, states: &mut [[f64; 6]], epochs: &mut [f64]) {}
@@ -540,8 +1747,9 @@ cspice_proc! {
     Return the position of a target body relative to an observing body, optionally corrected for
     light time (planetary aberration) and stellar aberration.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn spkpos(targ: &str, et: f64, frame: &str, abcorr: &str, obs: &str) -> ([f64; 3], f64) {}
+    pub fn spkpos(targ: &str, et: impl Into<Et>, frame: &str, abcorr: &str, obs: &str) -> ([f64; 3], f64) {}
 }
 
 cspice_proc! {
@@ -550,8 +1758,9 @@ cspice_proc! {
     relative to an observing body, optionally corrected for light
     time (planetary aberration) and stellar aberration.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
-    pub fn spkezr(targ: &str, et: f64, frame: &str, abcorr: &str, obs: &str) -> ([f64; 6], f64) {}
+    pub fn spkezr(targ: &str, et: impl Into<Et>, frame: &str, abcorr: &str, obs: &str) -> ([f64; 6], f64) {}
 }
 
 cspice_proc! {
@@ -559,6 +1768,7 @@ cspice_proc! {
     Convert a string representing an epoch to a double precision value representing the number of
     TDB seconds past the J2000 epoch corresponding to the input epoch.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn str2et(targ: &str) -> f64 {}
 }
@@ -571,30 +1781,32 @@ light time and stellar aberration.
 The surface of the target body may be represented by a triaxial
 ellipsoid or by topographic data provided by DSK files.
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn subpnt(
     method: &str,
     target: &str,
-    et: f64,
+    et: impl Into<Et>,
     fixref: &str,
     abcorr: &str,
     obsrvr: &str,
 ) -> ([f64; 3], f64, [f64; 3]) {
-    let method = cstr!(method);
-    let target = cstr!(target);
-    let fixref = cstr!(fixref);
-    let abcorr = cstr!(abcorr);
-    let obsrvr = cstr!(obsrvr);
+    let mut method = cstr!(method);
+    let mut target = cstr!(target);
+    let mut fixref = cstr!(fixref);
+    let mut abcorr = cstr!(abcorr);
+    let mut obsrvr = cstr!(obsrvr);
+    let et: Et = et.into();
     let mut sp = [0.0; 3];
     let mut et_sp = 0.0;
     let mut vec_sp = [0.0; 3];
     unsafe {
         crate::c::subpnt_c(
-            method,
-            target,
-            et,
-            fixref,
-            abcorr,
-            obsrvr,
+            mptr!(method),
+            mptr!(target),
+            et.0,
+            mptr!(fixref),
+            mptr!(abcorr),
+            mptr!(obsrvr),
             &mut sp as _,
             &mut et_sp,
             &mut vec_sp as _,
@@ -607,38 +1819,154 @@ cspice_proc! {
     /**
     Determine the intersection of a line-of-sight vector with the surface of an ellipsoid.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn surfpt(positn: [f64; 3], u: [f64; 3], a: f64, b: f64, c: f64) -> ([f64; 3], bool) {}
 }
 
+/**
+Add a name to the list of agents to notify whenever a member of a list of kernel variables is
+updated.
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+pub fn swpool(agent: &str, names: &[&str]) {
+    let mut agent = cstr!(agent);
+    let lenvals = names.iter().map(|name| name.len()).max().unwrap_or(0) + 1;
+    let names_ = join_strings(names, lenvals);
+    unsafe {
+        crate::c::swpool_c(
+            mptr!(agent),
+            names.len() as _,
+            lenvals as _,
+            names_.as_ptr() as *const c_void,
+        )
+    }
+}
+
+cspice_proc! {
+    /**
+    Return the state transformation matrix from one frame to another at a specified epoch.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn sxform(from: &str, to: &str, et: impl Into<Et>) -> [[f64; 6]; 6] {}
+}
+
+/**
+Set and retrieve the defaults associated with calendar input strings.
+
+`action` is either `"SET"` or `"GET"`, and `item` is either `"CALENDAR"`, `"SYSTEM"` or `"ZONE"`.
+The value of the item is returned, set to `value` by a `"SET"` action.
+
+This function has a [neat version][crate::neat::timdef].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_timdef))]
+pub fn timdef(action: &str, item: &str, value: &str, lenout: usize) -> String {
+    let mut action = cstr!(action);
+    let mut item = cstr!(item);
+    let mut value = cstr!(value);
+    value.resize(value.len().max(lenout), 0);
+    unsafe { crate::c::timdef_c(mptr!(action), mptr!(item), value.len() as i32, mptr!(value)) };
+    fcstr!(value)
+}
+
 /**
 Convert an input epoch represented in TDB seconds past the TDB epoch of J2000 to a character string formatted to the
 specifications of a user's format picture.
 
 This function has a [neat version][crate::neat::timout].
 */
-pub fn timout(et: f64, pictur: &str, lenout: usize) -> String {
-    let varout_0 = mallocstr!(lenout);
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_timout))]
+pub fn timout(et: impl Into<Et>, pictur: &str, lenout: usize) -> String {
+    let mut pictur = cstr!(pictur);
+    let mut varout_0 = mallocstr!(lenout);
     unsafe {
-        crate::c::timout_c(et, cstr!(pictur), lenout as i32, varout_0);
+        crate::c::timout_c(
+            Into::<Et>::into(et).0,
+            mptr!(pictur),
+            lenout as i32,
+            mptr!(varout_0),
+        );
     }
     fcstr!(varout_0)
 }
 
+/**
+Parse a time string and return seconds past the J2000 epoch on a formal calendar, with the
+diagnostic message explaining why the string could not be parsed, empty on success.
+
+This function has a [neat version][crate::neat::tparse].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_tparse))]
+pub fn tparse(string: &str, lenout: usize) -> (f64, String) {
+    let mut string = cstr!(string);
+    let mut sp2000 = 0.0;
+    let mut errmsg = mallocstr!(lenout);
+    unsafe { crate::c::tparse_c(mptr!(string), lenout as i32, &mut sp2000, mptr!(errmsg)) };
+    (sp2000, fcstr!(errmsg))
+}
+
+/**
+Create a time format picture suitable for use by the routine [`timout`] from a given sample time
+string, with whether the sample could be understood and the diagnostic message explaining why not.
+
+This function has a [neat version][crate::neat::tpictr].
+*/
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock, name = raw_tpictr))]
+pub fn tpictr(sample: &str, lenpictur: usize, lenerror: usize) -> (String, bool, String) {
+    let mut sample = cstr!(sample);
+    let mut pictur = mallocstr!(lenpictur);
+    let mut ok = 0;
+    let mut error = mallocstr!(lenerror);
+    unsafe {
+        crate::c::tpictr_c(
+            mptr!(sample),
+            lenpictur as i32,
+            lenerror as i32,
+            mptr!(pictur),
+            &mut ok,
+            mptr!(error),
+        )
+    };
+    (fcstr!(pictur), ok != 0, fcstr!(error))
+}
+
+cspice_proc! {
+    /**
+    Find the transformation to the right-handed frame having a given vector as a specified axis,
+    of index 1, 2 or 3, and having a second given vector lying in a specified coordinate plane.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn twovec(axdef: [f64; 3], indexa: i32, plndef: [f64; 3], indexp: i32) -> [[f64; 3]; 3] {}
+}
+
 /**
 Transform time from one uniform scale to another. The uniform time scales are
 TAI, GPS, TT, TDT, TDB, ET, JED, JDTDB, JDTDT.
 */
+#[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
 pub fn unitim(epoch: f64, insys: &str, outsys: &str) -> f64 {
-    let insys = cstr!(insys);
-    let outsys = cstr!(outsys);
-    unsafe { crate::c::unitim_c(epoch, insys, outsys) }
+    let mut insys = cstr!(insys);
+    let mut outsys = cstr!(outsys);
+    unsafe { crate::c::unitim_c(epoch, mptr!(insys), mptr!(outsys)) }
+}
+
+cspice_proc! {
+    /**
+    Convert an input time from Calendar or Julian Date format, UTC, to ephemeris seconds past
+    J2000.
+    */
+    #[fallible]
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn utc2et(utcstr: &str) -> f64 {}
 }
 
 cspice_proc! {
     /**
     Unload a SPICE kernel.
     */
+    #[fallible]
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn unload(name: &str) {}
 }
@@ -670,6 +1998,15 @@ cspice_proc! {
     pub fn vcrss(v1: [f64; 3], v2: [f64; 3]) -> [f64; 3] {}
 }
 
+cspice_proc! {
+    /**
+    Determine the rotation matrix and angular velocity of the rotation from a state transformation
+    matrix.
+    */
+    #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
+    pub fn xf2rav(xform: [[f64; 6]; 6]) -> ([[f64; 3]; 3], [f64; 3]) {}
+}
+
 cspice_proc! {
     /**
     Transpose a 3x3 matrix.
@@ -677,3 +2014,27 @@ cspice_proc! {
     #[cfg_attr(any(feature = "lock", doc), impl_for(SpiceLock))]
     pub fn xpose(m1: [[f64; 3]; 3]) -> [[f64; 3]; 3] {}
 }
+
+/**
+Split an array of `n` strings of length `lenout` filled by CSPICE.
+*/
+fn split_strings(buffer: &[std::os::raw::c_char], lenout: usize, n: usize) -> Vec<String> {
+    buffer
+        .chunks(lenout)
+        .take(n)
+        .map(|string| fcstr!(string))
+        .collect()
+}
+
+/**
+Join strings into an array of null-terminated strings of length `lenvals` to be sent to CSPICE.
+*/
+fn join_strings(strings: &[&str], lenvals: usize) -> Vec<std::os::raw::c_char> {
+    let mut buffer = crate::malloc!(std::os::raw::c_char, strings.len() * lenvals);
+    for (string, chunk) in strings.iter().zip(buffer.chunks_mut(lenvals)) {
+        for (byte, c) in string.bytes().zip(chunk.iter_mut()) {
+            *c = byte as std::os::raw::c_char;
+        }
+    }
+    buffer
+}
@@ -0,0 +1,148 @@
+/*!
+Spacecraft clock (SCLK) conversions.
+
+## Description
+
+A spacecraft clock reading is represented either as a string, such as `"1/0123456789.12345"`
+where the optional leading integer is the partition number, or as encoded ticks: a double precision
+count of the clock's smallest unit since the start of the first partition. Discrete ticks are
+integral, while continuous ticks may have a fractional part.
+
+An [`Sclk`] is bound to the clock of a spacecraft and converts between these representations and
+ephemeris time. The conversions rely on the SCLK kernel of the spacecraft and on a leapseconds
+kernel, and return a [`Result`] carrying the error signaled by CSPICE, for instance
+`SPICE(KERNELVARNOTFOUND)` when no SCLK kernel is loaded for the spacecraft.
+
+With the `lock` feature, the conversions take the [`SpiceLock`][crate::SpiceLock] as an additional
+argument.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/sclk.html).
+*/
+
+use crate::core::error::{try_call, Result};
+use crate::{neat, raw, Et};
+use spice_derive::with_lock;
+
+/**
+The clock of a spacecraft.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sclk {
+    id: i32,
+}
+
+impl Sclk {
+    /**
+    Clock of the spacecraft of the given NAIF ID, such as `-91` for Hera.
+    */
+    pub fn new(id: i32) -> Self {
+        Self { id }
+    }
+
+    /**
+    NAIF ID of the spacecraft.
+    */
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /**
+    Ephemeris time of a clock string.
+
+    See [scs2e_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scs2e_c.html).
+    */
+    #[with_lock]
+    pub fn string_to_et(&self, sclkch: &str) -> Result<Et> {
+        try_call(|| Et(raw::scs2e(self.id, sclkch)))
+    }
+
+    /**
+    Clock string of an ephemeris time, with its partition number.
+
+    See [sce2s_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2s_c.html).
+    */
+    #[with_lock]
+    pub fn et_to_string(&self, et: impl Into<Et>) -> Result<String> {
+        try_call(|| neat::sce2s(self.id, et))
+    }
+
+    /**
+    Ephemeris time of encoded ticks, either continuous or discrete.
+
+    See [sct2e_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sct2e_c.html).
+    */
+    #[with_lock]
+    pub fn ticks_to_et(&self, ticks: f64) -> Result<Et> {
+        try_call(|| Et(raw::sct2e(self.id, ticks)))
+    }
+
+    /**
+    Continuous ticks of an ephemeris time.
+
+    See [sce2c_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2c_c.html).
+    */
+    #[with_lock]
+    pub fn et_to_ticks(&self, et: impl Into<Et>) -> Result<f64> {
+        try_call(|| raw::sce2c(self.id, et))
+    }
+
+    /**
+    Discrete ticks of an ephemeris time, rounded to the nearest tick.
+
+    See [sce2t_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/sce2t_c.html).
+    */
+    #[with_lock]
+    pub fn et_to_discrete_ticks(&self, et: impl Into<Et>) -> Result<f64> {
+        try_call(|| raw::sce2t(self.id, et))
+    }
+
+    /**
+    Encode a clock string into ticks. The first partition is assumed if the string has no
+    partition number.
+
+    See [scencd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scencd_c.html).
+    */
+    #[with_lock]
+    pub fn encode(&self, sclkch: &str) -> Result<f64> {
+        try_call(|| raw::scencd(self.id, sclkch))
+    }
+
+    /**
+    Decode ticks into a clock string, with its partition number.
+
+    See [scdecd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scdecd_c.html).
+    */
+    #[with_lock]
+    pub fn decode(&self, ticks: f64) -> Result<String> {
+        try_call(|| neat::scdecd(self.id, ticks))
+    }
+
+    /**
+    Start and stop clock readings of each partition, in ticks.
+
+    See [scpart_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/scpart_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn partitions(&self) -> Result<Vec<(f64, f64)>> {
+        try_call(|| raw::scpart(self.id))
+    }
+
+    /**
+    Partition number, starting at 1, of the encoded ticks, or [`None`] if they are out of the
+    partitions.
+    */
+    #[with_lock]
+    pub fn partition_of(&self, ticks: f64) -> Result<Option<usize>> {
+        if ticks < 0.0 {
+            return Ok(None);
+        }
+        let mut elapsed = 0.0;
+        for (index, (start, stop)) in self.partitions_unlocked()?.into_iter().enumerate() {
+            elapsed += stop - start;
+            if ticks <= elapsed {
+                return Ok(Some(index + 1));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,196 @@
+use crate::core::lock::SpiceLock;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+use thiserror::Error;
+
+/// Error returned when a request sent to a `SpiceService` cannot be answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ServiceError {
+    /// The service stopped before answering the request.
+    #[error("SPICE service stopped before answering the request.")]
+    Stopped,
+    /// The request panicked, the service keeps running the other requests.
+    #[error("SPICE request panicked.")]
+    Panicked,
+}
+
+// A request, run on the thread owning the lock
+type Job = Box<dyn FnOnce(&SpiceLock) + Send>;
+
+/// A dedicated thread owning the `SpiceLock` and running the requests sent by its handles one at a
+/// time, in the order they were received.
+/// The thread runs until the service and all its handles are dropped.
+/// Only available with the `lock` feature enabled.
+pub struct SpiceService {
+    handle: SpiceHandle,
+    thread: JoinHandle<SpiceLock>,
+}
+
+impl SpiceService {
+    /// Start the service on a new thread, moving the lock to it.
+    pub fn new(lock: SpiceLock) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let thread = thread::Builder::new()
+            .name("spice".to_string())
+            .spawn(move || {
+                for job in receiver {
+                    job(&lock);
+                }
+                lock
+            })
+            .expect("Cannot spawn SPICE service thread.");
+        Self {
+            handle: SpiceHandle {
+                sender: Mutex::new(sender),
+            },
+            thread,
+        }
+    }
+
+    /// Attempt to acquire the lock and start the service.
+    /// Will be `Err` if a lock already exists.
+    pub fn try_acquire() -> Result<Self, &'static str> {
+        SpiceLock::try_acquire().map(Self::new)
+    }
+
+    /// A new handle sending requests to the service.
+    pub fn handle(&self) -> SpiceHandle {
+        self.handle.clone()
+    }
+
+    /// Stop the service and give the lock back.
+    /// Blocks until all handles are dropped and their requests are answered.
+    pub fn into_lock(self) -> SpiceLock {
+        drop(self.handle);
+        match self.thread.join() {
+            Ok(lock) => lock,
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+/// A cloneable handle to a `SpiceService`, which can be shared between threads.
+/// The requests are closures taking the lock, whose methods are the SPICE functions:
+///
+/// ```ignore
+/// let et = handle.call(|sl| sl.str2et("2027-MAR-23 16:00:00"))?;
+/// ```
+pub struct SpiceHandle {
+    // `Sender` is only `Sync` from Rust 1.72
+    sender: Mutex<Sender<Job>>,
+}
+
+impl Clone for SpiceHandle {
+    fn clone(&self) -> Self {
+        let sender = self.sender.lock().unwrap_or_else(|e| e.into_inner());
+        Self {
+            sender: Mutex::new(sender.clone()),
+        }
+    }
+}
+
+impl SpiceHandle {
+    /// Run a request on the service thread and block until it returns.
+    /// Will be `Err` if the request panicked or the service is stopped.
+    pub fn call<F, R>(&self, f: F) -> Result<R, ServiceError>
+    where
+        F: FnOnce(&SpiceLock) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (reply, result) = mpsc::sync_channel(1);
+        self.send(Box::new(move |lock| {
+            let _ = reply.send(run(lock, f));
+        }));
+        result.recv().unwrap_or(Err(ServiceError::Stopped))
+    }
+
+    /// Run a request on the service thread without blocking, returning a future resolving to its
+    /// result.
+    /// The future resolves to `Err` if the request panicked or the service is stopped.
+    pub fn call_async<F, R>(&self, f: F) -> SpiceFuture<R>
+    where
+        F: FnOnce(&SpiceLock) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+        let reply = Reply(Some(Arc::clone(&shared)));
+        self.send(Box::new(move |lock| reply.complete(run(lock, f))));
+        SpiceFuture(shared)
+    }
+
+    // If the service is stopped, the job is dropped with its reply, which answers with an error
+    fn send(&self, job: Job) {
+        let sender = self.sender.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = sender.send(job);
+    }
+}
+
+// A panicking request is reported to its caller, and must not stop the service for the other
+// handles
+fn run<F, R>(lock: &SpiceLock, f: F) -> Result<R, ServiceError>
+where
+    F: FnOnce(&SpiceLock) -> R,
+{
+    panic::catch_unwind(AssertUnwindSafe(|| f(lock))).map_err(|_| ServiceError::Panicked)
+}
+
+// A handle is shared between threads
+fn assert_send_sync<T: Send + Sync>() {}
+const _: fn() = assert_send_sync::<SpiceHandle>;
+
+// State shared between a `SpiceFuture` and the reply of its request
+struct Shared<R> {
+    result: Option<Result<R, ServiceError>>,
+    waker: Option<Waker>,
+}
+
+// Sending half of a `SpiceFuture`, answering with an error if dropped without sending
+struct Reply<R>(Option<Arc<Mutex<Shared<R>>>>);
+
+impl<R> Reply<R> {
+    fn complete(mut self, result: Result<R, ServiceError>) {
+        self.set(result);
+    }
+
+    fn set(&mut self, result: Result<R, ServiceError>) {
+        if let Some(shared) = self.0.take() {
+            let mut shared = shared.lock().unwrap_or_else(|e| e.into_inner());
+            shared.result = Some(result);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<R> Drop for Reply<R> {
+    fn drop(&mut self) {
+        self.set(Err(ServiceError::Stopped));
+    }
+}
+
+/// Future resolving to the result of a request sent with `SpiceHandle::call_async`.
+pub struct SpiceFuture<R>(Arc<Mutex<Shared<R>>>);
+
+impl<R> Future for SpiceFuture<R> {
+    type Output = Result<R, ServiceError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
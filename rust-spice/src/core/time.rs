@@ -0,0 +1,351 @@
+/*!
+Ephemeris time as a type of its own.
+
+## Description
+
+CSPICE represents epochs as ephemeris time (ET): TDB seconds past the J2000 epoch, as a double
+precision number. The same `f64` type is used for UTC seconds, Julian dates and durations, so an
+[`Et`] wraps the ephemeris time to tell it apart. The geometry functions accept any
+`impl Into<Et>`, so both an [`Et`] and a bare `f64` of TDB seconds can be given.
+
+Parsing and formatting rely on the leapseconds kernel loaded, and return a [`Result`] carrying the
+error signaled by CSPICE, if any. [`Display`][fmt::Display] does not call CSPICE and prints the TDB
+seconds past J2000, use [`Et::format`] or [`Et::format_default`] for a calendar date.
+
+With the `chrono` feature, an [`Et`] converts from and to a `chrono::DateTime<Utc>` with
+`Et::from_chrono` and `Et::to_chrono`, through the UTC seconds past J2000 and
+[`deltet`][crate::raw::deltet], so the leapseconds come from the kernel loaded. With the `hifitime`
+feature, an [`Et`] converts from and to a `hifitime::Epoch` with `Et::from_hifitime` and
+`Et::to_hifitime`, through the TAI seconds past J2000 and [`unitim`][crate::raw::unitim]. Both
+conversions are also available through `TryFrom`.
+
+With the `lock` feature, the conversions calling CSPICE take the [`SpiceLock`][crate::SpiceLock]
+as an additional argument, and the [`FromStr`] and `TryFrom` implementations, which cannot take it,
+are not available: use `str2et` and the conversion methods instead.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/time.html).
+*/
+
+use crate::core::error::Result;
+#[cfg(any(feature = "chrono", not(feature = "lock")))]
+use crate::core::error::SpiceError;
+use crate::core::TIME_FORMAT;
+use crate::fallible;
+use serde::{Deserialize, Serialize};
+use spice_derive::with_lock;
+use std::cmp::Ordering;
+#[cfg(all(any(feature = "chrono", feature = "hifitime"), not(feature = "lock")))]
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(not(feature = "lock"))]
+use std::str::FromStr;
+use std::time::Duration;
+
+/**
+Ephemeris time: TDB seconds past the J2000 epoch.
+
+Epochs are totally ordered as by [`f64::total_cmp`].
+*/
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Et(pub f64);
+
+impl Et {
+    /**
+    The J2000 epoch, 2000 JAN 01 12:00:00 TDB.
+    */
+    pub const J2000: Self = Self(0.0);
+
+    /**
+    Convert an epoch from a uniform time scale, such as `"TAI"`, `"TT"`, `"JDTDB"` or `"JED"`.
+
+    See [unitim_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unitim_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn from_time_scale(epoch: f64, insys: &str) -> Result<Self> {
+        fallible::unitim(epoch, insys, "TDB").map(Self)
+    }
+
+    /**
+    Convert the epoch to a uniform time scale, such as `"TAI"`, `"TT"`, `"JDTDB"` or `"JED"`.
+
+    See [unitim_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unitim_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn to_time_scale(self, outsys: &str) -> Result<f64> {
+        fallible::unitim(self.0, "TDB", outsys)
+    }
+
+    /**
+    Epoch of a Julian date in the TDB time scale.
+    */
+    #[with_lock]
+    pub fn from_julian_date(jd: f64) -> Result<Self> {
+        Self::from_time_scale_unlocked(jd, "JDTDB")
+    }
+
+    /**
+    Julian date of the epoch in the TDB time scale.
+    */
+    #[with_lock]
+    pub fn julian_date(self) -> Result<f64> {
+        self.to_time_scale_unlocked("JDTDB")
+    }
+
+    /**
+    Format the epoch following a format picture.
+
+    See [timout_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/timout_c.html).
+    */
+    #[with_lock]
+    pub fn format(self, pictur: &str) -> Result<String> {
+        fallible::timout(self, pictur)
+    }
+
+    /**
+    Format the epoch following [`TIME_FORMAT`], such as `2027-MAR-23 16:00:00`.
+
+    See [timout_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/timout_c.html).
+    */
+    #[with_lock]
+    pub fn format_default(self) -> Result<String> {
+        fallible::timout(self, TIME_FORMAT)
+    }
+}
+
+impl From<f64> for Et {
+    fn from(et: f64) -> Self {
+        Self(et)
+    }
+}
+
+impl From<Et> for f64 {
+    fn from(et: Et) -> Self {
+        et.0
+    }
+}
+
+/**
+Parse a time string.
+
+See [str2et_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/str2et_c.html).
+*/
+#[cfg(not(feature = "lock"))]
+impl FromStr for Et {
+    type Err = SpiceError;
+
+    fn from_str(targ: &str) -> Result<Self> {
+        fallible::str2et(targ).map(Self)
+    }
+}
+
+/**
+Print the epoch as TDB seconds past J2000, with the formatting options of [`f64`], without calling
+CSPICE.
+*/
+impl fmt::Display for Et {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" TDB seconds past J2000")
+    }
+}
+
+impl PartialEq for Et {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Et {}
+
+impl PartialOrd for Et {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Et {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for Et {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl Add<Duration> for Et {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+        Self(self.0 + duration.as_secs_f64())
+    }
+}
+
+impl AddAssign<Duration> for Et {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Et {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self {
+        Self(self.0 - duration.as_secs_f64())
+    }
+}
+
+impl SubAssign<Duration> for Et {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+/**
+Seconds elapsed from `other` to `self`, negative if `self` is earlier.
+*/
+impl Sub for Et {
+    type Output = f64;
+
+    fn sub(self, other: Self) -> f64 {
+        self.0 - other.0
+    }
+}
+
+/**
+UTC seconds past J2000 of the Unix epoch, ignoring leapseconds as both Unix time and CSPICE do.
+*/
+#[cfg(feature = "chrono")]
+const UNIX_EPOCH_UTC: f64 = -946_728_000.0;
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl Et {
+    /**
+    Convert a UTC date, using the leapseconds kernel loaded.
+
+    See [deltet_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/deltet_c.html).
+    */
+    #[with_lock]
+    pub fn from_chrono(date: chrono::DateTime<chrono::Utc>) -> Result<Self> {
+        let utc = UNIX_EPOCH_UTC
+            + date.timestamp() as f64
+            + f64::from(date.timestamp_subsec_nanos()) * 1e-9;
+        fallible::deltet(utc, "UTC").map(|delta| Self(utc + delta))
+    }
+
+    /**
+    Convert to a UTC date, using the leapseconds kernel loaded.
+
+    See [deltet_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/deltet_c.html).
+    */
+    #[with_lock]
+    pub fn to_chrono(self) -> Result<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        let utc = self.0 - fallible::deltet(self.0, "ET")?;
+        let unix = utc - UNIX_EPOCH_UTC;
+        let seconds = unix.floor();
+        let nanos = (((unix - seconds) * 1e9).round() as u32).min(999_999_999);
+        chrono::Utc
+            .timestamp_opt(seconds as i64, nanos)
+            .single()
+            .ok_or_else(|| SpiceError {
+                short: "SPICE(VALUEOUTOFRANGE)".to_string(),
+                long: format!("The epoch {} is out of the range of chrono dates.", self.0),
+                traceback: String::new(),
+            })
+    }
+}
+
+/**
+Convert a UTC date, using the leapseconds kernel loaded, as [`Et::from_chrono`].
+*/
+#[cfg(all(feature = "chrono", not(feature = "lock")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Et {
+    type Error = SpiceError;
+
+    fn try_from(date: chrono::DateTime<chrono::Utc>) -> Result<Self> {
+        Self::from_chrono(date)
+    }
+}
+
+/**
+Convert to a UTC date, using the leapseconds kernel loaded, as [`Et::to_chrono`].
+*/
+#[cfg(all(feature = "chrono", not(feature = "lock")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<Et> for chrono::DateTime<chrono::Utc> {
+    type Error = SpiceError;
+
+    fn try_from(et: Et) -> Result<Self> {
+        et.to_chrono()
+    }
+}
+
+/**
+TAI epoch of J2000 in hifitime, 2000 JAN 01 12:00:00 TAI.
+*/
+#[cfg(feature = "hifitime")]
+fn hifitime_j2000() -> hifitime::Epoch {
+    hifitime::Epoch::from_gregorian_tai_hms(2000, 1, 1, 12, 0, 0)
+}
+
+#[cfg(feature = "hifitime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+impl Et {
+    /**
+    Convert a hifitime epoch, using the constants of the leapseconds kernel loaded.
+
+    See [unitim_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unitim_c.html).
+    */
+    #[with_lock]
+    pub fn from_hifitime(epoch: hifitime::Epoch) -> Result<Self> {
+        Self::from_time_scale_unlocked((epoch - hifitime_j2000()).to_seconds(), "TAI")
+    }
+
+    /**
+    Convert to a hifitime epoch, using the constants of the leapseconds kernel loaded.
+
+    See [unitim_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/unitim_c.html).
+    */
+    #[with_lock]
+    pub fn to_hifitime(self) -> Result<hifitime::Epoch> {
+        let tai = self.to_time_scale_unlocked("TAI")?;
+        Ok(hifitime_j2000() + tai * hifitime::Unit::Second)
+    }
+}
+
+/**
+Convert a hifitime epoch, using the constants of the leapseconds kernel loaded, as
+[`Et::from_hifitime`].
+*/
+#[cfg(all(feature = "hifitime", not(feature = "lock")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+impl TryFrom<hifitime::Epoch> for Et {
+    type Error = SpiceError;
+
+    fn try_from(epoch: hifitime::Epoch) -> Result<Self> {
+        Self::from_hifitime(epoch)
+    }
+}
+
+/**
+Convert to a hifitime epoch, using the constants of the leapseconds kernel loaded, as
+[`Et::to_hifitime`].
+*/
+#[cfg(all(feature = "hifitime", not(feature = "lock")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "hifitime")))]
+impl TryFrom<Et> for hifitime::Epoch {
+    type Error = SpiceError;
+
+    fn try_from(et: Et) -> Result<Self> {
+        et.to_hifitime()
+    }
+}
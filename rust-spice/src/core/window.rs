@@ -0,0 +1,270 @@
+/*!
+SPICE windows: ordered sets of disjoint time intervals.
+
+## Description
+
+A [`Window`] is a double precision [`Cell`] holding the endpoints of its intervals, as produced or
+consumed by the coverage and geometry finder routines. Inserting an interval merges it with the
+intervals it overlaps, and grows the window if needed.
+
+With the `lock` feature, the operations calling CSPICE, such as [`Window::insert_interval`] or
+[`Window::union`], take the [`SpiceLock`][crate::SpiceLock] as an additional argument, and the
+windows are no longer built from a [`Vec`] of intervals.
+
+See the [C documentation](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/windows.html).
+*/
+
+use crate::c;
+use crate::core::cell::{Cell, Output};
+use spice_derive::with_lock;
+use std::fmt;
+
+/**
+An ordered set of disjoint intervals `[start, end]`, usually of ephemeris time.
+*/
+#[derive(Clone)]
+pub struct Window {
+    cell: Cell<f64>,
+}
+
+impl Window {
+    /**
+    Declare an empty window able to hold up to `size` intervals.
+    */
+    pub fn new(size: usize) -> Self {
+        Self {
+            cell: Cell::<f64>::new(2 * size),
+        }
+    }
+
+    /**
+    Number of intervals in the window.
+    */
+    pub fn len(&self) -> usize {
+        self.cell.len() / 2
+    }
+
+    /**
+    Whether the window has no interval.
+    */
+    pub fn is_empty(&self) -> bool {
+        self.cell.is_empty()
+    }
+
+    /**
+    Maximum number of intervals the window can hold before growing.
+    */
+    pub fn capacity(&self) -> usize {
+        self.cell.capacity() / 2
+    }
+
+    /**
+    Interval at index, or [`None`] if out of bounds.
+    */
+    pub fn get(&self, index: usize) -> Option<(f64, f64)> {
+        Some((*self.cell.get(2 * index)?, *self.cell.get(2 * index + 1)?))
+    }
+
+    /**
+    Iterate over the intervals of the window.
+    */
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            window: self,
+            index: 0,
+        }
+    }
+
+    /**
+    Insert an interval into the window, merging it with the intervals it overlaps.
+
+    See [wninsd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wninsd_c.html).
+    */
+    #[with_lock]
+    pub fn insert_interval(&mut self, start: f64, end: f64) {
+        self.cell.reserve(2);
+        unsafe { c::wninsd_c(start, end, self.as_mut_ptr()) }
+    }
+
+    /**
+    Union of two windows.
+
+    See [wnunid_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnunid_c.html).
+    */
+    #[with_lock(unlocked)]
+    pub fn union(&self, other: &Self) -> Self {
+        self.operate(other, c::wnunid_c)
+    }
+
+    /**
+    Intersection of two windows.
+
+    See [wnintd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnintd_c.html).
+    */
+    #[with_lock]
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.operate(other, c::wnintd_c)
+    }
+
+    /**
+    Difference of two windows: the parts of `self` not covered by `other`.
+
+    See [wndifd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wndifd_c.html).
+    */
+    #[with_lock]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.operate(other, c::wndifd_c)
+    }
+
+    /**
+    Apply an operation on copies of both windows, into a new window large enough for any result.
+    */
+    fn operate(
+        &self,
+        other: &Self,
+        operation: unsafe extern "C" fn(*mut c::SpiceCell, *mut c::SpiceCell, *mut c::SpiceCell),
+    ) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        let mut output = Self::new(self.len() + other.len());
+        unsafe { operation(a.as_mut_ptr(), b.as_mut_ptr(), output.as_mut_ptr()) };
+        output
+    }
+
+    /**
+    Contract each interval of the window, moving its start by `left` and its end by `-right`.
+    Intervals shrinking to nothing are removed.
+
+    See [wncond_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wncond_c.html).
+    */
+    #[with_lock]
+    pub fn contract(&mut self, left: f64, right: f64) {
+        unsafe { c::wncond_c(left, right, self.as_mut_ptr()) }
+    }
+
+    /**
+    Expand each interval of the window, moving its start by `-left` and its end by `right`.
+    Intervals overlapping after expansion are merged.
+
+    See [wnexpd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnexpd_c.html).
+    */
+    #[with_lock]
+    pub fn expand(&mut self, left: f64, right: f64) {
+        unsafe { c::wnexpd_c(left, right, self.as_mut_ptr()) }
+    }
+
+    /**
+    Fill the gaps between intervals which are not longer than `small`.
+
+    See [wnfild_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnfild_c.html).
+    */
+    #[with_lock]
+    pub fn fill_gaps(&mut self, small: f64) {
+        unsafe { c::wnfild_c(small, self.as_mut_ptr()) }
+    }
+
+    /**
+    Remove the intervals which are not longer than `small`.
+
+    See [wnfltd_c](https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/cspice/wnfltd_c.html).
+    */
+    #[with_lock]
+    pub fn filter_small(&mut self, small: f64) {
+        unsafe { c::wnfltd_c(small, self.as_mut_ptr()) }
+    }
+
+    /**
+    Sum of the lengths of the intervals.
+    */
+    pub fn measure(&self) -> f64 {
+        self.iter().map(|(start, end)| end - start).sum()
+    }
+
+    /**
+    Whether a point is contained in one of the intervals.
+    */
+    pub fn contains(&self, et: f64) -> bool {
+        self.iter().any(|(start, end)| start <= et && et <= end)
+    }
+
+    /**
+    Double precision cell holding the endpoints of the intervals.
+    */
+    pub fn as_cell(&self) -> &Cell<f64> {
+        &self.cell
+    }
+
+    /**
+    Mutable pointer to the underlying CSPICE cell, to be sent to CSPICE.
+    */
+    pub fn as_mut_ptr(&mut self) -> *mut c::SpiceCell {
+        self.cell.as_mut_ptr()
+    }
+}
+
+impl Output for Window {
+    fn is_full(&self) -> bool {
+        self.cell.len() + 2 > self.cell.capacity()
+    }
+
+    fn grow(&mut self) {
+        self.cell.grow()
+    }
+}
+
+impl fmt::Debug for Window {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(not(feature = "lock"))]
+impl From<Vec<(f64, f64)>> for Window {
+    fn from(intervals: Vec<(f64, f64)>) -> Self {
+        let mut window = Self::new(intervals.len());
+        for (start, end) in intervals {
+            window.insert_interval(start, end);
+        }
+        window
+    }
+}
+
+impl From<Window> for Vec<(f64, f64)> {
+    fn from(window: Window) -> Self {
+        window.iter().collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a Window {
+    type Item = (f64, f64);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/**
+Iterator over the intervals of a [`Window`].
+*/
+pub struct Iter<'a> {
+    window: &'a Window,
+    index: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let interval = self.window.get(self.index)?;
+        self.index += 1;
+        Some(interval)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.window.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
@@ -40,8 +40,30 @@ pub(crate) mod core;
 pub(crate) use crate::core::*;
 
 // These items need to be exposed regardless of whether 'lock' is enabled or not
-pub use crate::core::{DLADSC, DSKDSC, MAX_LEN_OUT, TIME_FORMAT, TIME_FORMAT_SIZE};
+pub use crate::core::{
+    Cell, CellElement, Et, SpiceError, Window, DLADSC, DSKDSC, MAX_LEN_OUT, TIME_FORMAT,
+    TIME_FORMAT_SIZE,
+};
+
+// The high-level types calling CSPICE take the lock themselves when it is enabled
+#[cfg(feature = "lock")]
+pub use crate::core::coordinates::Coordinates;
+#[cfg(feature = "lock")]
+pub use crate::core::frame::{Frame, FrameClass};
+#[cfg(feature = "lock")]
+pub use crate::core::kernel::{
+    loaded_kernels, Kernel, KernelGuard, KernelKind, KernelSet, LoadedKernel, ParseKernelKindError,
+};
+#[cfg(feature = "lock")]
+pub use crate::core::linalg;
+#[cfg(feature = "lock")]
+pub use crate::core::pool;
+#[cfg(feature = "lock")]
+pub use crate::core::sclk::Sclk;
 
 #[cfg(any(feature = "lock", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "lock")))]
 pub use crate::core::lock::SpiceLock;
+#[cfg(any(feature = "lock", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "lock")))]
+pub use crate::core::service::{ServiceError, SpiceFuture, SpiceHandle, SpiceService};
@@ -202,7 +202,7 @@ fn get_temp_filepath(fname: &str) -> String {
 /// Deletes specified file if it exists
 fn delete_if_exists(file: &std::path::Path) {
     if file.exists() {
-        std::fs::remove_file(&file).unwrap();
+        std::fs::remove_file(file).unwrap();
     }
 }
 
@@ -210,15 +210,17 @@ fn delete_if_exists(file: &std::path::Path) {
 /// Necessary in some SPK writer related tests as spkcls_c will fail with no segments present
 fn junk_spkw09_c(handle: i32) {
     const N_STATES: usize = 4;
+    let mut frame = spice::cstr!("J2000");
+    let mut segid = spice::cstr!("Segment ID");
     unsafe {
         spice::c::spkw09_c(
             handle,
             399,
             10,
-            spice::cstr!("J2000"),
+            frame.as_mut_ptr(),
             0.0,
             (N_STATES - 1) as f64,
-            spice::cstr!("Segment ID"),
+            segid.as_mut_ptr(),
             3,
             N_STATES as i32,
             [[0f64; 6]; N_STATES].as_mut_ptr(),
@@ -233,14 +235,9 @@ fn junk_spkw09_c(handle: i32) {
 /// Opens a new SPK file for writing
 fn open_test_spk(filepath: &str) -> i32 {
     let mut handle = 0;
-    unsafe {
-        spice::c::spkopn_c(
-            spice::cstr!(filepath),
-            spice::cstr!("SPK Kernel File"),
-            0,
-            &mut handle,
-        )
-    }
+    let mut filepath = spice::cstr!(filepath);
+    let mut ifname = spice::cstr!("SPK Kernel File");
+    unsafe { spice::c::spkopn_c(filepath.as_mut_ptr(), ifname.as_mut_ptr(), 0, &mut handle) }
     handle
 }
 
@@ -397,9 +394,12 @@ fn kdata() {
         "/Users/gregoireh/data/spice-kernels/hera/kernels/dsk/g_08438mm_lgt_obj_didb_0000n00000_v002.bds"
     );
     assert_eq!(filtyp, "DSK");
-    assert_eq!(source, "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    assert_eq!(
+        source,
+        "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm"
+    );
     assert!(handle.is_positive());
-    assert_eq!(found, true);
+    assert!(found);
 
     spice::kclear();
 }
@@ -414,14 +414,115 @@ fn cell() {
 
     let cell = spice::dskobj(&file);
 
-    assert_eq!(cell.card, 1);
-    assert_eq!(cell.get_data_int(0), -658031);
+    assert_eq!(cell.len(), 1);
+    assert_eq!(cell[0], -658031);
 
-    assert_eq!(spice::bodc2n(cell.get_data_int(0)).0, "DIMORPHOS");
+    assert_eq!(spice::bodc2n(cell[0]).0, "DIMORPHOS");
 
     spice::kclear();
 }
 
+#[test]
+#[serial]
+fn cell_int() {
+    let mut cell = spice::Cell::<i32>::new(10);
+
+    assert!(cell.is_empty());
+    assert_eq!(cell.capacity(), 10);
+
+    cell.insert(3);
+    cell.insert(-1);
+    cell.insert(3);
+    cell.insert(7);
+
+    assert_eq!(cell.len(), 3);
+    assert_eq!(cell.get(3), None);
+    assert!(cell.contains(&7));
+    assert_eq!(cell.iter().copied().collect::<Vec<_>>(), vec![-1, 3, 7]);
+
+    let vec: Vec<i32> = cell.into();
+    assert_eq!(vec, vec![-1, 3, 7]);
+}
+
+#[test]
+#[serial]
+fn cell_grow() {
+    let mut cell = spice::Cell::<i32>::new(0);
+
+    for i in 0..5 {
+        cell.push(i);
+    }
+    assert_eq!(cell.len(), 5);
+    assert!(cell.capacity() >= 5);
+    assert_eq!(Vec::from(cell), vec![0, 1, 2, 3, 4]);
+
+    let mut set = spice::Cell::<String>::new(1, 8);
+    set.insert("MOON");
+    set.insert("EARTH");
+    set.insert("SUN");
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec!["EARTH", "MOON", "SUN"]);
+}
+
+#[test]
+#[serial]
+fn cell_double() {
+    let mut cell = spice::Cell::from(vec![2.0, 1.0, 2.0]);
+
+    assert!(!cell.is_set());
+    assert_eq!(cell[2], 2.0);
+
+    cell.validate();
+
+    assert!(cell.is_set());
+    assert_eq!(Vec::from(cell), vec![1.0, 2.0]);
+}
+
+#[test]
+#[serial]
+fn cell_string() {
+    let mut cell = spice::Cell::<String>::new(5, 8);
+
+    cell.insert("EARTH");
+    cell.insert("MOON");
+    cell.insert("EARTH");
+
+    assert_eq!(cell.len(), 2);
+    assert_eq!(&cell[0], "EARTH");
+    assert_eq!(&cell[1], "MOON");
+
+    let cell: spice::Cell<String> = vec!["SUN".to_string(), "MARS".to_string()].into();
+
+    assert_eq!(cell.iter().collect::<Vec<_>>(), vec!["SUN", "MARS"]);
+}
+
+#[test]
+#[serial]
+fn cell_string_multibyte() {
+    // Longer than the declared length, and cut in the middle of a character by CSPICE
+    let mut cell = spice::Cell::<String>::new(2, 4);
+    cell.push("ÉTÉ");
+    cell.push("ÇÀ");
+    assert_eq!(cell.iter().collect::<Vec<_>>(), vec!["ÉT", "Ç"]);
+
+    let mut set = spice::Cell::<String>::new(1, 4);
+    set.insert("ÇÀ");
+    assert_eq!(&set[0], "Ç");
+}
+
+#[test]
+#[serial]
+fn cell_set_operations() {
+    let a = spice::Cell::from(vec![1, 2, 3, 4]);
+    let b = spice::Cell::from(vec![6, 4, 2]);
+
+    assert_eq!(Vec::from(a.union(&b)), vec![1, 2, 3, 4, 6]);
+    assert_eq!(Vec::from(a.intersection(&b)), vec![2, 4]);
+    assert_eq!(Vec::from(a.difference(&b)), vec![1, 3]);
+
+    assert_eq!(a.len(), 4);
+    assert_eq!(b[0], 6);
+}
+
 #[test]
 #[serial]
 fn bodfnd() {
@@ -436,3 +537,1436 @@ fn bodfnd() {
 
     spice::kclear();
 }
+
+#[test]
+#[serial]
+fn fallible_str2et() {
+    spice::kclear();
+
+    // No leapseconds kernel loaded.
+    let error = spice::fallible::str2et("2027-MAR-23 16:00:00").unwrap_err();
+    assert_eq!(error.short, "SPICE(NOLEAPSECONDS)");
+    assert!(!error.long.is_empty());
+    assert!(error.traceback.contains("str2et"));
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    // The error status has been reset, the next call succeeds.
+    let et = spice::fallible::str2et("2027-MAR-23 16:00:00").unwrap();
+    assert_relative_eq!(et, 859089669.1856234, epsilon = f64::EPSILON);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn fallible_spkpos() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+
+    let error = spice::fallible::spkpos("NOT A BODY", et, "J2000", "NONE", "HERA").unwrap_err();
+    assert_eq!(error.short, "SPICE(IDCODENOTFOUND)");
+
+    let (position, _) = spice::fallible::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA").unwrap();
+    assert_relative_eq!(position[0], 19.880764225600004, epsilon = f64::EPSILON);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn fallible_furnsh() {
+    let error = spice::fallible::furnsh("/not/a/kernel.tm").unwrap_err();
+    assert_eq!(error.short, "SPICE(NOSUCHFILE)");
+
+    let error = spice::fallible::pxform("J2000", "NOT A FRAME", 0.0).unwrap_err();
+    assert_eq!(error.short, "SPICE(UNKNOWNFRAME)");
+
+    let found = spice::fallible::bodfnd(399, "RADII").unwrap();
+    assert!(!found);
+}
+
+#[test]
+#[serial]
+fn window() {
+    let mut window = spice::Window::new(1);
+
+    window.insert_interval(1.0, 3.0);
+    window.insert_interval(7.0, 11.0);
+    window.insert_interval(2.0, 5.0);
+
+    assert_eq!(window.len(), 2);
+    assert!(window.capacity() >= 2);
+    assert_eq!(window.get(0), Some((1.0, 5.0)));
+    assert_eq!(window.get(2), None);
+    assert_eq!(window.measure(), 8.0);
+    assert!(window.contains(7.0));
+    assert!(!window.contains(6.0));
+
+    let intervals: Vec<(f64, f64)> = window.into();
+    assert_eq!(intervals, vec![(1.0, 5.0), (7.0, 11.0)]);
+}
+
+#[test]
+#[serial]
+fn window_operations() {
+    let a = spice::Window::from(vec![(1.0, 3.0), (7.0, 11.0)]);
+    let b = spice::Window::from(vec![(2.0, 8.0)]);
+
+    assert_eq!(Vec::from(a.union(&b)), vec![(1.0, 11.0)]);
+    assert_eq!(Vec::from(a.intersect(&b)), vec![(2.0, 3.0), (7.0, 8.0)]);
+    assert_eq!(Vec::from(a.difference(&b)), vec![(1.0, 2.0), (8.0, 11.0)]);
+
+    let mut window = a;
+    window.expand(1.0, 1.0);
+    assert_eq!(
+        window.iter().collect::<Vec<_>>(),
+        vec![(0.0, 4.0), (6.0, 12.0)]
+    );
+
+    window.fill_gaps(2.0);
+    assert_eq!(window.iter().collect::<Vec<_>>(), vec![(0.0, 12.0)]);
+
+    window.contract(2.0, 1.0);
+    assert_eq!(window.iter().collect::<Vec<_>>(), vec![(2.0, 11.0)]);
+
+    window.filter_small(10.0);
+    assert!(window.is_empty());
+}
+
+#[test]
+#[serial]
+fn spkcov() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let (file, _, _, _, found) = spice::kdata(0, "SPK");
+    assert!(found);
+
+    let ids = spice::spkobj(&file);
+    assert!(!ids.is_empty());
+
+    let cover = spice::spkcov(&file, ids[0]);
+    let (start, end) = cover.get(0).unwrap();
+    assert!(start < end);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn spkcov_loaded() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let (hera, found) = spice::bodn2c("HERA");
+    assert!(found);
+
+    assert!(spice::spkobj_loaded().contains(&hera));
+
+    let cover = spice::spkcov_loaded(hera);
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    assert!(cover.contains(et));
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn ckcov() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let (file, _, _, _, found) = spice::kdata(0, "CK");
+    assert!(found);
+
+    let ids = spice::ckobj(&file);
+    assert!(!ids.is_empty());
+
+    let cover = spice::ckcov(&file, ids[0], false, "INTERVAL", 0.0, "TDB");
+    assert!(!cover.is_empty());
+
+    let loaded = spice::ckcov_loaded(ids[0], false, "INTERVAL", 0.0, "TDB");
+    assert!(loaded.measure() >= cover.measure());
+    assert!(spice::ckobj_loaded().contains(&ids[0]));
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfdist() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let distance = |et: f64| {
+        let (position, _) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+        spice::vdot(position, position).sqrt()
+    };
+    let refval = 1.01 * distance(et);
+
+    let result = spice::gfdist(
+        "DIMORPHOS",
+        "NONE",
+        "HERA",
+        "<",
+        refval,
+        0.0,
+        3600.0,
+        1000,
+        &cnfine,
+    );
+
+    assert!(result.contains(et));
+    for (left, right) in result.iter() {
+        assert!(cnfine.contains(left) && cnfine.contains(right));
+        assert!(distance(0.5 * (left + right)) < refval);
+    }
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfposc() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, start + 2.0 * 86400.0)]);
+
+    let (position, _) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+    let refval = 1.01 * spice::vdot(position, position).sqrt();
+
+    let by_distance = spice::gfdist(
+        "DIMORPHOS",
+        "NONE",
+        "HERA",
+        "<",
+        refval,
+        0.0,
+        3600.0,
+        1000,
+        &cnfine,
+    );
+    let by_range = spice::gfposc(
+        "DIMORPHOS",
+        "J2000",
+        "NONE",
+        "HERA",
+        "LATITUDINAL",
+        "RADIUS",
+        "<",
+        refval,
+        0.0,
+        3600.0,
+        1000,
+        &cnfine,
+    );
+
+    assert_eq!(by_distance.len(), by_range.len());
+    for ((left_d, right_d), (left_r, right_r)) in by_distance.iter().zip(by_range.iter()) {
+        assert_relative_eq!(left_d, left_r, epsilon = 1e-3);
+        assert_relative_eq!(right_d, right_r, epsilon = 1e-3);
+    }
+
+    spice::kclear();
+}
+
+/// Checks that a GF search result holds exactly the epochs of `(start, end)` where the condition
+/// is true, by testing the middle of each interval and of each gap between them
+fn assert_gf_result(
+    result: &spice::Window,
+    (start, end): (f64, f64),
+    condition: impl Fn(f64) -> bool,
+) {
+    let mut bounds = vec![start];
+    for (left, right) in result.iter() {
+        assert!(start <= left && right <= end);
+        bounds.push(left);
+        bounds.push(right);
+    }
+    bounds.push(end);
+    for (i, pair) in bounds.windows(2).enumerate() {
+        // Skip the singleton intervals and the empty gaps
+        if pair[1] - pair[0] > 1.0 {
+            let middle = 0.5 * (pair[0] + pair[1]);
+            assert_eq!(condition(middle), i % 2 == 1, "at {}", middle);
+        }
+    }
+}
+
+/// Defines the instrument RUST_SPICE_FOV, with a circular field of view of 10 degrees around a
+/// boresight fixed in J2000
+fn define_test_fov(boresight: [f64; 3]) {
+    let mut names = spice::pool::get_strings("NAIF_BODY_NAME").unwrap_or_default();
+    let mut codes = spice::pool::get_i32s("NAIF_BODY_CODE").unwrap_or_default();
+    names.push("RUST_SPICE_FOV".to_string());
+    codes.push(-999_001);
+    let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+    spice::pool::put_strings("NAIF_BODY_NAME", &names).unwrap();
+    spice::pool::put_i32s("NAIF_BODY_CODE", &codes).unwrap();
+
+    spice::pool::put_strings("INS-999001_FOV_CLASS_SPEC", &["ANGLES"]).unwrap();
+    spice::pool::put_strings("INS-999001_FOV_SHAPE", &["CIRCLE"]).unwrap();
+    spice::pool::put_strings("INS-999001_FOV_FRAME", &["J2000"]).unwrap();
+    spice::pool::put_f64s("INS-999001_BORESIGHT", &boresight).unwrap();
+    spice::pool::put_f64s(
+        "INS-999001_FOV_REF_VECTOR",
+        &spice::vcrss(boresight, [0.0, 0.0, 1.0]),
+    )
+    .unwrap();
+    spice::pool::put_f64s("INS-999001_FOV_REF_ANGLE", &[10.0]).unwrap();
+    spice::pool::put_strings("INS-999001_FOV_ANGLE_UNITS", &["DEGREES"]).unwrap();
+}
+
+#[test]
+#[serial]
+fn gfilum() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    // Point of the Earth under HERA at et, rotating away from it afterwards
+    let (spoint, _, _) = spice::subpnt(
+        "NEAR POINT/ELLIPSOID",
+        "EARTH",
+        et,
+        "IAU_EARTH",
+        "NONE",
+        "HERA",
+    );
+    let emission = |et: f64| {
+        spice::illumf(
+            "ELLIPSOID",
+            "EARTH",
+            "SUN",
+            et,
+            "IAU_EARTH",
+            "NONE",
+            "HERA",
+            spoint,
+        )
+        .4
+    };
+    let refval = 60f64.to_radians();
+
+    let result = spice::gfilum(
+        "ELLIPSOID",
+        "EMISSION",
+        "EARTH",
+        "SUN",
+        "IAU_EARTH",
+        "NONE",
+        "HERA",
+        spoint,
+        "<",
+        refval,
+        0.0,
+        600.0,
+        1000,
+        &cnfine,
+    );
+
+    assert!(result.contains(et));
+    assert_gf_result(&result, (start, end), |et| emission(et) < refval);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfoclt() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let result = spice::gfoclt(
+        "ANY",
+        "EARTH",
+        "ELLIPSOID",
+        "IAU_EARTH",
+        "SUN",
+        "ELLIPSOID",
+        "IAU_SUN",
+        "NONE",
+        "HERA",
+        600.0,
+        &cnfine,
+    );
+
+    // Positive codes tell the second target is occulted by the first one
+    assert_gf_result(&result, (start, end), |et| {
+        spice::occult(
+            "EARTH",
+            "ELLIPSOID",
+            "IAU_EARTH",
+            "SUN",
+            "ELLIPSOID",
+            "IAU_SUN",
+            "NONE",
+            "HERA",
+            et,
+        ) > 0
+    });
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfpa() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let phase = |et: f64| {
+        let (sun, _) = spice::spkpos("SUN", et, "J2000", "NONE", "EARTH");
+        let (hera, _) = spice::spkpos("HERA", et, "J2000", "NONE", "EARTH");
+        spice::vsep(sun, hera)
+    };
+    let refval = 1.01 * phase(et);
+
+    let result = spice::gfpa(
+        "EARTH", "SUN", "NONE", "HERA", "<", refval, 0.0, 3600.0, 1000, &cnfine,
+    );
+
+    assert!(result.contains(et));
+    assert_gf_result(&result, (start, end), |et| phase(et) < refval);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfrfov() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let boresight = [1.0, 1.0, 0.0];
+    define_test_fov(boresight);
+
+    let along = spice::gfrfov(
+        "RUST_SPICE_FOV",
+        boresight,
+        "J2000",
+        "NONE",
+        "HERA",
+        3600.0,
+        &cnfine,
+    );
+    let opposite = spice::gfrfov(
+        "RUST_SPICE_FOV",
+        [-1.0, -1.0, 0.0],
+        "J2000",
+        "NONE",
+        "HERA",
+        3600.0,
+        &cnfine,
+    );
+
+    assert_eq!(Vec::from(along), vec![(start, end)]);
+    assert!(opposite.is_empty());
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfrr() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let range_rate = |et: f64| {
+        let (state, _) = spice::spkezr("EARTH", et, "J2000", "NONE", "HERA");
+        let position = [state[0], state[1], state[2]];
+        let velocity = [state[3], state[4], state[5]];
+        spice::vdot(position, velocity) / spice::vdot(position, position).sqrt()
+    };
+    let refval = range_rate(et) + 1e-4;
+
+    let result = spice::gfrr(
+        "EARTH", "NONE", "HERA", "<", refval, 0.0, 3600.0, 1000, &cnfine,
+    );
+
+    assert!(result.contains(et));
+    assert_gf_result(&result, (start, end), |et| range_rate(et) < refval);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfsep() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let separation = |et: f64| {
+        let (earth, _) = spice::spkpos("EARTH", et, "J2000", "NONE", "HERA");
+        let (sun, _) = spice::spkpos("SUN", et, "J2000", "NONE", "HERA");
+        spice::vsep(earth, sun)
+    };
+    let refval = 1.01 * separation(et);
+
+    let result = spice::gfsep(
+        "EARTH", "POINT", "NULL", "SUN", "POINT", "NULL", "NONE", "HERA", "<", refval, 0.0, 3600.0,
+        1000, &cnfine,
+    );
+
+    assert!(result.contains(et));
+    assert_gf_result(&result, (start, end), |et| separation(et) < refval);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfsntc() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    // Ray from HERA to the Earth at et, whose intercept follows the rotation of the Earth
+    let (dvec, _) = spice::spkpos("EARTH", et, "J2000", "NONE", "HERA");
+    let longitude = |et: f64| {
+        let (spoint, _, _, found) = spice::sincpt(
+            "ELLIPSOID",
+            "EARTH",
+            et,
+            "IAU_EARTH",
+            "NONE",
+            "HERA",
+            "J2000",
+            dvec,
+        );
+        assert!(found);
+        spice::reclat(spoint).1
+    };
+
+    let result = spice::gfsntc(
+        "EARTH",
+        "IAU_EARTH",
+        "ELLIPSOID",
+        "NONE",
+        "HERA",
+        "J2000",
+        dvec,
+        "LATITUDINAL",
+        "LONGITUDE",
+        ">",
+        0.0,
+        0.0,
+        3600.0,
+        1000,
+        &cnfine,
+    );
+
+    assert!(!result.is_empty());
+    assert_gf_result(&result, (start, end), |et| longitude(et) > 0.0);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfsubc() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let longitude = |et: f64| {
+        let (spoint, _, _) = spice::subpnt(
+            "NEAR POINT/ELLIPSOID",
+            "EARTH",
+            et,
+            "IAU_EARTH",
+            "NONE",
+            "HERA",
+        );
+        spice::reclat(spoint).1
+    };
+
+    let result = spice::gfsubc(
+        "EARTH",
+        "IAU_EARTH",
+        "NEAR POINT/ELLIPSOID",
+        "NONE",
+        "HERA",
+        "LATITUDINAL",
+        "LONGITUDE",
+        ">",
+        0.0,
+        0.0,
+        3600.0,
+        1000,
+        &cnfine,
+    );
+
+    assert!(!result.is_empty());
+    assert_gf_result(&result, (start, end), |et| longitude(et) > 0.0);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gftfov() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let end = start + 2.0 * 86400.0;
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, end)]);
+
+    let (boresight, _) = spice::spkpos("EARTH", et, "J2000", "NONE", "HERA");
+    define_test_fov(boresight);
+
+    let result = spice::gftfov(
+        "RUST_SPICE_FOV",
+        "EARTH",
+        "POINT",
+        " ",
+        "NONE",
+        "HERA",
+        3600.0,
+        &cnfine,
+    );
+
+    assert!(result.contains(et));
+    assert_gf_result(&result, (start, end), |et| {
+        let (earth, _) = spice::spkpos("EARTH", et, "J2000", "NONE", "HERA");
+        spice::vsep(earth, boresight) < 10f64.to_radians()
+    });
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfuds() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, start + 2.0 * 86400.0)]);
+
+    let distance = |et: f64| {
+        let (position, _) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+        spice::vdot(position, position).sqrt()
+    };
+    let decreasing = |et: f64| distance(et + 1.0) < distance(et);
+    let refval = 1.01 * distance(et);
+
+    let expected = spice::gfdist(
+        "DIMORPHOS",
+        "NONE",
+        "HERA",
+        "<",
+        refval,
+        0.0,
+        3600.0,
+        1000,
+        &cnfine,
+    );
+    let result = spice::gfuds(
+        distance, decreasing, "<", refval, 0.0, 3600.0, 1000, &cnfine,
+    );
+
+    assert_eq!(result.len(), expected.len());
+    for ((left, right), (expected_left, expected_right)) in result.iter().zip(expected.iter()) {
+        assert_relative_eq!(left, expected_left, epsilon = 1.0);
+        assert_relative_eq!(right, expected_right, epsilon = 1.0);
+    }
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfudb() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let start = spice::str2et("2027-MAR-23 00:00:00");
+    let et = start + 43200.0;
+    let cnfine = spice::Window::from(vec![(start, start + 2.0 * 86400.0)]);
+
+    let distance = |et: f64| {
+        let (position, _) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+        spice::vdot(position, position).sqrt()
+    };
+    let refval = 1.01 * distance(et);
+
+    let result = spice::gfudb(|et| distance(et) < refval, 3600.0, &cnfine);
+
+    assert!(result.contains(et));
+    for (left, right) in result.iter() {
+        assert!(distance(0.5 * (left + right)) < refval);
+    }
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn gfudb_panic() {
+    let cnfine = spice::Window::from(vec![(0.0, 86400.0)]);
+
+    let outcome = std::panic::catch_unwind(|| {
+        spice::gfudb(
+            |et| if et > 0.0 { panic!("user panic") } else { true },
+            3600.0,
+            &cnfine,
+        )
+    });
+
+    let payload = outcome.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"user panic"));
+
+    let result = spice::gfudb(|_| true, 3600.0, &cnfine);
+    assert_eq!(Vec::from(result), vec![(0.0, 86400.0)]);
+}
+
+#[test]
+#[serial]
+fn gfudb_large_result() {
+    // True on every other period of 10 seconds, more intervals than the initial result capacity
+    let periods = 2 * spice::cell::CELL_MAXID + 100;
+    let cnfine = spice::Window::from(vec![(0.0, 10.0 * periods as f64)]);
+
+    let result = spice::gfudb(|et| (et / 10.0).floor() as i64 % 2 == 0, 4.0, &cnfine);
+
+    assert_eq!(result.len(), periods / 2);
+    assert!(result.capacity() > spice::cell::CELL_MAXID);
+    assert_relative_eq!(result.get(0).unwrap().1, 10.0, epsilon = 1e-3);
+}
+
+#[test]
+#[serial]
+fn kernel_guard() {
+    spice::kclear();
+    let path = "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm";
+
+    {
+        let guard = spice::Kernel::load(path).unwrap();
+        assert_eq!(guard.path(), path);
+        assert!(!guard.children().is_empty());
+        assert_eq!(spice::ktotal("META"), 1);
+        assert!(spice::ktotal("SPK") > 0);
+    }
+
+    assert_eq!(spice::ktotal("ALL"), 0);
+
+    let outcome = std::panic::catch_unwind(|| {
+        let _guard = spice::Kernel::load(path).unwrap();
+        panic!("user panic");
+    });
+
+    assert!(outcome.is_err());
+    assert_eq!(spice::ktotal("ALL"), 0);
+
+    let kept = spice::Kernel::load(path).unwrap().keep();
+    assert_eq!(spice::ktotal("META"), 1);
+    spice::unload(&kept);
+}
+
+#[test]
+#[serial]
+fn kernel_set() {
+    spice::kclear();
+    let path = "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm";
+
+    let error = spice::KernelSet::load([path, "/not/a/kernel.tm"]).unwrap_err();
+    assert_eq!(error.short, "SPICE(NOSUCHFILE)");
+    assert_eq!(spice::ktotal("ALL"), 0);
+
+    let set = spice::KernelSet::load([path]).unwrap();
+    assert_eq!(set.len(), 1);
+    assert_eq!(set.paths().len() as i32, spice::ktotal("ALL"));
+
+    drop(set);
+    assert_eq!(spice::ktotal("ALL"), 0);
+}
+
+#[test]
+#[serial]
+fn loaded_kernels() {
+    spice::kclear();
+    let path = "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm";
+    let _guard = spice::Kernel::load(path).unwrap();
+
+    let kernels = spice::loaded_kernels(&spice::KernelKind::ALL).collect::<Vec<_>>();
+    assert_eq!(kernels.len() as i32, spice::ktotal("ALL"));
+    assert_eq!(kernels[0].path, path);
+    assert_eq!(kernels[0].kind, spice::KernelKind::Meta);
+    assert_eq!(kernels[0].source, None);
+    for kernel in &kernels[1..] {
+        assert_eq!(kernel.source.as_deref(), Some(path));
+    }
+
+    let spks = spice::loaded_kernels(&[spice::KernelKind::Spk]).collect::<Vec<_>>();
+    assert!(!spks.is_empty());
+    for spk in &spks {
+        assert_eq!(spk.kind, spice::KernelKind::Spk);
+        assert_ne!(spk.handle, 0);
+        assert_eq!(spice::LoadedKernel::find(&spk.path).as_ref(), Some(spk));
+    }
+
+    assert_eq!(spice::LoadedKernel::find("/not/a/kernel.bsp"), None);
+    assert_eq!("CK".parse(), Ok(spice::KernelKind::Ck));
+    assert!("BSP".parse::<spice::KernelKind>().is_err());
+    assert_eq!(spice::loaded_kernels(&[]).count(), 0);
+}
+
+#[test]
+#[serial]
+fn pool() {
+    spice::kclear();
+
+    assert_eq!(spice::pool::describe("RUST_SPICE_DOUBLES"), None);
+    assert_eq!(spice::pool::get_f64s("RUST_SPICE_DOUBLES"), None);
+
+    spice::pool::put_f64s("RUST_SPICE_DOUBLES", &[1.0, 2.5, -3.0]).unwrap();
+    spice::pool::put_i32s("RUST_SPICE_INTEGERS", &[1, 2]).unwrap();
+    spice::pool::put_strings("RUST_SPICE_STRINGS", &["a", "longer string"]).unwrap();
+
+    assert_eq!(
+        spice::pool::describe("RUST_SPICE_DOUBLES"),
+        Some((spice::pool::VariableType::Numeric, 3))
+    );
+    assert_eq!(
+        spice::pool::describe("RUST_SPICE_STRINGS"),
+        Some((spice::pool::VariableType::Character, 2))
+    );
+    assert_eq!(
+        spice::pool::get_f64s("RUST_SPICE_DOUBLES"),
+        Some(vec![1.0, 2.5, -3.0])
+    );
+    assert_eq!(
+        spice::pool::get_i32s("RUST_SPICE_INTEGERS"),
+        Some(vec![1, 2])
+    );
+    assert_eq!(
+        spice::pool::get_strings("RUST_SPICE_STRINGS"),
+        Some(vec!["a".to_string(), "longer string".to_string()])
+    );
+    assert_eq!(spice::pool::get_strings("RUST_SPICE_DOUBLES"), None);
+    assert_eq!(
+        spice::raw::gdpool("RUST_SPICE_DOUBLES", 1, 5),
+        (vec![2.5, -3.0], true)
+    );
+    assert_eq!(spice::raw::gdpool("RUST_SPICE_MISSING", 0, 5), (vec![], false));
+
+    let mut names = spice::pool::names("RUST_SPICE_*");
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "RUST_SPICE_DOUBLES",
+            "RUST_SPICE_INTEGERS",
+            "RUST_SPICE_STRINGS"
+        ]
+    );
+
+    let error = spice::pool::put_f64s("", &[1.0]).unwrap_err();
+    assert!(!error.short.is_empty());
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn pool_watcher() {
+    spice::kclear();
+
+    let watcher = spice::pool::PoolWatcher::new(&["BODY399_RADII", "RUST_SPICE_WATCHED"]).unwrap();
+    assert_eq!(watcher.names(), ["BODY399_RADII", "RUST_SPICE_WATCHED"]);
+
+    assert!(watcher.poll());
+    assert!(!watcher.poll());
+
+    spice::pool::put_f64s("RUST_SPICE_NOT_WATCHED", &[1.0]).unwrap();
+    assert_eq!(watcher.changes().count(), 0);
+
+    spice::pool::put_f64s("RUST_SPICE_WATCHED", &[1.0]).unwrap();
+    spice::pool::put_f64s("RUST_SPICE_WATCHED", &[2.0]).unwrap();
+    let changes = watcher.changes().collect::<Vec<_>>();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].names, watcher.names());
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+    assert!(watcher.poll());
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn et() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et: spice::Et = "2027-MAR-23 16:00:00".parse().unwrap();
+    assert_relative_eq!(et.0, 859089669.1856234, epsilon = f64::EPSILON);
+    assert_eq!(et.to_string(), "859089669.1856234 TDB seconds past J2000");
+    assert_eq!(format!("{:.1}", et), "859089669.2 TDB seconds past J2000");
+    assert_eq!(
+        et.format(spice::TIME_FORMAT).unwrap(),
+        "2027-MAR-23 16:00:00"
+    );
+    assert_eq!(et.format_default().unwrap(), "2027-MAR-23 16:00:00");
+    assert_eq!(
+        et.format("YYYY-DOY").unwrap(),
+        spice::timout(et, "YYYY-DOY")
+    );
+
+    let later = et + std::time::Duration::from_secs(3600);
+    assert_eq!(
+        later.format(spice::TIME_FORMAT).unwrap(),
+        "2027-MAR-23 17:00:00"
+    );
+    assert_eq!(later - et, 3600.0);
+    assert_eq!(later - std::time::Duration::from_secs(3600), et);
+    assert!(et < later);
+    assert_eq!(std::cmp::max(et, later), later);
+
+    let jd = spice::Et::J2000.julian_date().unwrap();
+    assert_eq!(jd, 2451545.0);
+    assert_eq!(spice::Et::from_julian_date(jd).unwrap(), spice::Et::J2000);
+
+    let (position, _) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+    let (expected_position, _) = spice::spkpos("DIMORPHOS", et.0, "J2000", "NONE", "HERA");
+    assert_eq!(position, expected_position);
+
+    let json = serde_json::to_string(&et).unwrap();
+    assert_eq!(json, et.0.to_string());
+    assert_eq!(serde_json::from_str::<spice::Et>(&json).unwrap(), et);
+
+    let error = "not a time".parse::<spice::Et>().unwrap_err();
+    assert!(!error.short.is_empty());
+
+    spice::kclear();
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+#[serial]
+fn et_chrono() {
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::convert::TryFrom;
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let date = Utc.with_ymd_and_hms(2027, 3, 23, 16, 0, 0).unwrap();
+    let et = spice::Et::try_from(date).unwrap();
+    let expected: spice::Et = "2027-MAR-23 16:00:00".parse().unwrap();
+    assert_relative_eq!(et.0, expected.0, epsilon = 1e-6);
+    assert_eq!(DateTime::<Utc>::try_from(et).unwrap(), date);
+    assert_eq!(spice::Et::from_chrono(date).unwrap(), et);
+    assert_eq!(et.to_chrono().unwrap(), date);
+
+    spice::kclear();
+}
+
+#[cfg(feature = "hifitime")]
+#[test]
+#[serial]
+fn et_hifitime() {
+    use hifitime::Epoch;
+    use std::convert::TryFrom;
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let epoch = Epoch::from_gregorian_utc_hms(2027, 3, 23, 16, 0, 0);
+    let et = spice::Et::try_from(epoch).unwrap();
+    let expected: spice::Et = "2027-MAR-23 16:00:00".parse().unwrap();
+    assert_relative_eq!(et.0, expected.0, epsilon = 1e-6);
+    let back = Epoch::try_from(et).unwrap();
+    assert!((back - epoch).abs().to_seconds() < 1e-6);
+    assert_eq!(spice::Et::from_hifitime(epoch).unwrap(), et);
+    assert_eq!(et.to_hifitime().unwrap(), back);
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn time_conversions() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::utc2et("2027-MAR-23 16:00:00");
+    assert_eq!(et, spice::str2et("2027-MAR-23 16:00:00"));
+
+    assert_eq!(spice::et2utc(et, "C", 0), "2027 MAR 23 16:00:00");
+    assert_eq!(spice::et2utc(et, "ISOC", 3), "2027-03-23T16:00:00.000");
+    assert_eq!(spice::et2utc(et, "D", 0), "2027-082 // 16:00:00");
+    assert_eq!(spice::et2utc(et, "J", 5), "JD 2461488.16667");
+    assert!(spice::etcal(et).starts_with("2027 MAR 23 16:01:09"));
+
+    assert_eq!(spice::tparse("2027-MAR-23 16:00:00"), Ok(859089600.0));
+    assert!(!spice::tparse("not a time").unwrap_err().is_empty());
+
+    let pictur = spice::tpictr("2027-03-23T16:00:00").unwrap();
+    assert_eq!(pictur, "YYYY-MM-DDTHR:MN:SC");
+    assert_eq!(spice::timout(et, &pictur), "2027-03-23T16:00:00");
+    assert!(spice::tpictr("not a time").is_err());
+
+    /// Restores the default calendar and time system when dropped, even if an assertion fails
+    struct TimeDefaults;
+
+    impl Drop for TimeDefaults {
+        fn drop(&mut self) {
+            spice::timdef("SET", "CALENDAR", "GREGORIAN");
+            spice::timdef("SET", "SYSTEM", "UTC");
+        }
+    }
+
+    let defaults = TimeDefaults;
+    assert_eq!(spice::timdef("GET", "CALENDAR", ""), "GREGORIAN");
+    assert_eq!(spice::timdef("SET", "SYSTEM", "TDB"), "TDB");
+    assert_eq!(spice::timdef("GET", "SYSTEM", ""), "TDB");
+    assert_eq!(spice::str2et("2000-JAN-01 12:00:00"), 0.0);
+    drop(defaults);
+    assert_eq!(spice::timdef("GET", "SYSTEM", ""), "UTC");
+
+    assert!(spice::fallible::utc2et("not a time").is_err());
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn sclk() {
+    spice::kclear();
+    let hera = spice::Sclk::new(-91);
+
+    let error = hera.string_to_et("1/0").unwrap_err();
+    assert!(!error.short.is_empty());
+
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::Et::from(spice::str2et("2027-MAR-23 16:00:00"));
+
+    let ticks = hera.et_to_ticks(et).unwrap();
+    assert_relative_eq!(hera.ticks_to_et(ticks).unwrap().0, et.0, epsilon = 1e-3);
+
+    let discrete = hera.et_to_discrete_ticks(et).unwrap();
+    assert_eq!(discrete, discrete.round());
+    assert!((discrete - ticks).abs() <= 0.5);
+
+    let string = hera.et_to_string(et).unwrap();
+    assert_eq!(hera.encode(&string).unwrap(), discrete);
+    assert_eq!(hera.decode(discrete).unwrap(), string);
+    assert_relative_eq!(hera.string_to_et(&string).unwrap().0, et.0, epsilon = 1.0);
+
+    let partitions = hera.partitions().unwrap();
+    assert!(!partitions.is_empty());
+    assert_eq!(hera.partition_of(0.0).unwrap(), Some(1));
+    assert_eq!(hera.partition_of(-1.0).unwrap(), None);
+    assert!(string.starts_with(&format!(
+        "{}/",
+        hera.partition_of(discrete).unwrap().unwrap()
+    )));
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn ckgp() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    let ticks = spice::raw::sce2c(-91, et);
+
+    let (cmat, clkout) = spice::ckgp(-91000, ticks, 256.0, "J2000").unwrap();
+    assert!((clkout - ticks).abs() <= 256.0);
+    let rotation = spice::pxform("J2000", "HERA_SPACECRAFT", spice::raw::sct2e(-91, clkout));
+    for (i, row) in rotation.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            assert_relative_eq!(cmat[(i, j)], value, epsilon = 1e-9);
+        }
+    }
+
+    let (cmat_av, av, _) = spice::ckgpav(-91000, ticks, 256.0, "J2000").unwrap();
+    assert_relative_eq!(cmat_av, cmat, epsilon = 1e-9);
+    assert!(av.norm() < 1.0);
+
+    assert!(spice::ckgp(-91000, -1.0, 0.0, "J2000").is_none());
+    assert!(spice::fallible::ckgp(-91000, ticks, 256.0, "NOT_A_FRAME").is_err());
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn sxform() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    let xform = spice::sxform("J2000", "IAU_EARTH", et);
+    let (rot, av) = spice::xf2rav(xform);
+    let rotation = spice::pxform("J2000", "IAU_EARTH", et);
+    for (row, expected_row) in rot.iter().zip(rotation.iter()) {
+        for (value, expected) in row.iter().zip(expected_row.iter()) {
+            assert_relative_eq!(value, expected, epsilon = 1e-12);
+        }
+    }
+    assert_relative_eq!(av[2], 7.292115e-5, epsilon = 1e-8);
+
+    let rebuilt = spice::rav2xf(rot, av);
+    for (row, expected_row) in rebuilt.iter().zip(xform.iter()) {
+        for (value, expected) in row.iter().zip(expected_row.iter()) {
+            assert_relative_eq!(value, expected, epsilon = 1e-12);
+        }
+    }
+
+    let (state, _) = spice::spkezr("DIMORPHOS", et, "J2000", "NONE", "EARTH");
+    let (expected_state, _) = spice::spkezr("DIMORPHOS", et, "IAU_EARTH", "NONE", "EARTH");
+    let transformed = spice::sxform_state(state, "J2000", "IAU_EARTH", et);
+    for (value, expected) in transformed.iter().zip(expected_state.iter()) {
+        assert_relative_eq!(value, expected, epsilon = 1e-6, max_relative = 1e-9);
+    }
+
+    assert!(spice::fallible::sxform("J2000", "NOT_A_FRAME", et).is_err());
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn frame() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let id = spice::namfrm("IAU_EARTH");
+    assert_ne!(id, 0);
+    assert_eq!(spice::frmnam(id), "IAU_EARTH");
+    assert_eq!(spice::namfrm("NOT_A_FRAME"), 0);
+    assert_eq!(spice::frmnam(0), "");
+
+    let (center, class, class_id, found) = spice::frinfo(id);
+    assert!(found);
+    assert_eq!(center, 399);
+    assert_eq!(class, 2);
+    assert_eq!(class_id, 399);
+    assert_eq!(
+        spice::ccifrm(class, class_id),
+        (id, "IAU_EARTH".to_string(), 399, true)
+    );
+    assert_eq!(spice::cidfrm(399), (id, "IAU_EARTH".to_string(), true));
+    assert_eq!(spice::cnmfrm("EARTH"), (id, "IAU_EARTH".to_string(), true));
+
+    let earth = spice::Frame::body_fixed("EARTH").unwrap();
+    assert_eq!(earth.name(), "IAU_EARTH");
+    assert_eq!(earth.to_string(), "IAU_EARTH");
+    assert_eq!(earth.id(), id);
+    assert_eq!(earth.class(), spice::FrameClass::Pck);
+    assert_eq!(earth.center(), 399);
+    assert_eq!(spice::Frame::body_fixed_of(399), Some(earth.clone()));
+    assert_eq!(
+        spice::Frame::from_class(spice::FrameClass::Pck, 399),
+        Some(earth.clone())
+    );
+
+    let j2000 = spice::Frame::from_name("j2000").unwrap();
+    assert_eq!(j2000.name(), "J2000");
+    assert_eq!(j2000.class(), spice::FrameClass::Inertial);
+    assert!(spice::Frame::from_name("NOT_A_FRAME").is_none());
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+    assert_eq!(
+        j2000.rotation_to(&earth, et).unwrap(),
+        spice::linalg::pxform("J2000", "IAU_EARTH", et)
+    );
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn coordinates() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let (radius, lon, lat) = spice::reclat([1.0, 1.0, 0.0]);
+    assert_relative_eq!(radius, 2f64.sqrt(), epsilon = 1e-12);
+    assert_relative_eq!(lon, std::f64::consts::FRAC_PI_4, epsilon = 1e-12);
+    assert_relative_eq!(lat, 0.0, epsilon = 1e-12);
+
+    let (re, f) = (6378.1366, 1.0 / 298.257);
+    let systems = [
+        spice::Coordinates::Rectangular,
+        spice::Coordinates::Latitudinal,
+        spice::Coordinates::RaDec,
+        spice::Coordinates::Spherical,
+        spice::Coordinates::Cylindrical,
+        spice::Coordinates::Geodetic { re, f },
+        spice::Coordinates::Planetographic {
+            body: "EARTH".to_string(),
+            re,
+            f,
+        },
+        spice::Coordinates::AzEl {
+            azccw: true,
+            elplsz: true,
+        },
+    ];
+    let state = [7000.0, -1200.0, 3500.0, 1.5, 6.8, -0.3];
+    let position = [state[0], state[1], state[2]];
+    let dt = 1e-3;
+    let later = [
+        state[0] + state[3] * dt,
+        state[1] + state[4] * dt,
+        state[2] + state[5] * dt,
+    ];
+
+    for system in systems.iter() {
+        let coords = system.from_rectangular(position).unwrap();
+        let back = system.to_rectangular(coords).unwrap();
+        for (value, expected) in back.iter().zip(position.iter()) {
+            assert_relative_eq!(value, expected, epsilon = 1e-6);
+        }
+
+        let converted = system.state_from_rectangular(state).unwrap();
+        let rates = system.from_rectangular(later).unwrap();
+        for k in 0..3 {
+            assert_relative_eq!(converted[k], coords[k], epsilon = 1e-9);
+            assert_relative_eq!(
+                converted[3 + k],
+                (rates[k] - coords[k]) / dt,
+                epsilon = 1e-6,
+                max_relative = 1e-4
+            );
+        }
+
+        let back = system.state_to_rectangular(converted).unwrap();
+        for (value, expected) in back.iter().zip(state.iter()) {
+            assert_relative_eq!(value, expected, epsilon = 1e-6);
+        }
+    }
+
+    let error = spice::Coordinates::Latitudinal
+        .jacobian_from_rectangular([0.0, 0.0, 1.0])
+        .unwrap_err();
+    assert_eq!(error.short, "SPICE(POINTONZAXIS)");
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn linalg_conversion() {
+    let rows = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    let matrix = spice::linalg::to_matrix(rows);
+    assert_eq!(matrix[(0, 1)], 2.0);
+    assert_eq!(matrix[(1, 0)], 4.0);
+    assert_eq!(matrix.row(2)[0], 7.0);
+    assert_eq!(spice::linalg::from_matrix(&matrix), rows);
+
+    let vector = na::Vector3::new(1.0, 0.0, -1.0);
+    assert_eq!(spice::linalg::mxv(&matrix, &vector), matrix * vector,);
+    assert_eq!(spice::linalg::xpose(&matrix), matrix.transpose());
+
+    let v1 = na::Vector3::new(1.0, 0.0, 0.0);
+    let v2 = na::Vector3::new(0.0, 2.0, 0.0);
+    assert_eq!(spice::linalg::vcrss(&v1, &v2), v1.cross(&v2));
+    assert_eq!(spice::linalg::vdot(&v1, &v2), 0.0);
+    assert_relative_eq!(
+        spice::linalg::vsep(&v1, &v2),
+        std::f64::consts::FRAC_PI_2,
+        epsilon = f64::EPSILON
+    );
+
+    let state = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let typed = spice::linalg::State::from(state);
+    assert_eq!(typed.velocity, na::Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(<[f64; 6]>::from(typed), state);
+}
+
+#[test]
+#[serial]
+fn linalg() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+
+    let (position, lt) = spice::linalg::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+    let (expected_position, expected_lt) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+    assert_eq!(position, na::Vector3::from(expected_position));
+    assert_eq!(lt, expected_lt);
+
+    let (state, _) = spice::linalg::spkezr("DIMORPHOS", et, "J2000", "NONE", "HERA");
+    let (expected_state, _) = spice::spkezr("DIMORPHOS", et, "J2000", "NONE", "HERA");
+    assert_eq!(<[f64; 6]>::from(state), expected_state);
+
+    let rotation = spice::linalg::pxform("J2000", "IAU_EARTH", et);
+    let expected = spice::pxform("J2000", "IAU_EARTH", et);
+    assert_relative_eq!(
+        rotation * na::Vector3::from(expected_position),
+        na::Vector3::from(spice::mxv(expected, expected_position)),
+        epsilon = 1e-6
+    );
+    assert_relative_eq!(
+        spice::linalg::pxfrm2("J2000", "IAU_EARTH", et, et),
+        rotation,
+        epsilon = 1e-12
+    );
+
+    spice::kclear();
+}
+
+#[test]
+#[serial]
+fn rotations() {
+    use std::f64::consts::FRAC_PI_2;
+
+    let x = [1.0, 0.0, 0.0];
+    let y = [0.0, 1.0, 0.0];
+    let z = [0.0, 0.0, 1.0];
+
+    let vector_rotation = spice::axisar(z, FRAC_PI_2);
+    for (value, expected) in spice::mxv(vector_rotation, x).iter().zip(y.iter()) {
+        assert_relative_eq!(value, expected, epsilon = 1e-15);
+    }
+    let frame_rotation = spice::rotate(FRAC_PI_2, 3);
+    for (value, expected) in spice::mxv(frame_rotation, x)
+        .iter()
+        .zip([0.0, -1.0, 0.0].iter())
+    {
+        assert_relative_eq!(value, expected, epsilon = 1e-15);
+    }
+    let rotated = spice::rotmat(vector_rotation, FRAC_PI_2, 3);
+    let expected =
+        spice::linalg::to_matrix(frame_rotation) * spice::linalg::to_matrix(vector_rotation);
+    assert_relative_eq!(spice::linalg::to_matrix(rotated), expected, epsilon = 1e-15);
+
+    let axis = [1.0 / 3f64.sqrt(); 3];
+    let angle = 0.7;
+    let r = spice::axisar(axis, angle);
+    let q = spice::m2q(r);
+    let half = (angle / 2.0).sin();
+    let expected_q = [
+        (angle / 2.0).cos(),
+        half * axis[0],
+        half * axis[1],
+        half * axis[2],
+    ];
+    for (value, expected) in q.iter().zip(expected_q.iter()) {
+        assert_relative_eq!(value, expected, epsilon = 1e-15);
+    }
+    assert_relative_eq!(
+        spice::linalg::to_matrix(spice::q2m(q)),
+        spice::linalg::to_matrix(r),
+        epsilon = 1e-15
+    );
+    let (found_axis, found_angle) = spice::raxisa(r);
+    assert_relative_eq!(found_angle, angle, epsilon = 1e-14);
+    for (value, expected) in found_axis.iter().zip(axis.iter()) {
+        assert_relative_eq!(value, expected, epsilon = 1e-14);
+    }
+
+    let unit = spice::linalg::to_unit_quaternion(q);
+    assert_relative_eq!(
+        unit.to_rotation_matrix(),
+        spice::linalg::to_rotation(r),
+        epsilon = 1e-15
+    );
+    assert_eq!(spice::linalg::from_unit_quaternion(&unit), q);
+
+    let q2 = spice::m2q(frame_rotation);
+    let product = spice::linalg::to_unit_quaternion(spice::qxq(q, q2));
+    assert_relative_eq!(
+        product,
+        unit * spice::linalg::to_unit_quaternion(q2),
+        epsilon = 1e-15
+    );
+
+    let m = spice::eul2m(0.3, -0.2, 0.1, 3, 1, 3);
+    let (angle3, angle2, angle1) = spice::m2eul(m, 3, 1, 3);
+    assert_relative_eq!(angle3, 0.3, epsilon = 1e-14);
+    assert_relative_eq!(angle2, -0.2, epsilon = 1e-14);
+    assert_relative_eq!(angle1, 0.1, epsilon = 1e-14);
+    assert!(spice::fallible::eul2m(0.3, -0.2, 0.1, 3, 3, 1).is_err());
+
+    let m = spice::twovec(y, 1, [-1.0, 0.0, 0.0], 2);
+    for (value, expected) in spice::mxv(m, y).iter().zip(x.iter()) {
+        assert_relative_eq!(value, expected, epsilon = 1e-15);
+    }
+    assert!(spice::fallible::twovec(y, 1, y, 2).is_err());
+
+    let rate = 0.01;
+    let t: f64 = 3.0;
+    let q = [(rate * t / 2.0).cos(), 0.0, 0.0, (rate * t / 2.0).sin()];
+    let dq = [
+        -rate / 2.0 * (rate * t / 2.0).sin(),
+        0.0,
+        0.0,
+        rate / 2.0 * (rate * t / 2.0).cos(),
+    ];
+    let av = spice::qdq2av(q, dq);
+    assert_relative_eq!(av[0], 0.0, epsilon = 1e-15);
+    assert_relative_eq!(av[1], 0.0, epsilon = 1e-15);
+    assert_relative_eq!(av[2].abs(), rate, epsilon = 1e-15);
+}
+
+#[test]
+#[serial]
+fn et2lst() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let et = spice::str2et("2027-MAR-23 16:00:00");
+
+    let (hr, mn, sc, time, ampm) = spice::et2lst(et, 399, 0.0, "PLANETOCENTRIC");
+    assert_eq!(hr, 15);
+    assert_eq!(time, format!("{:02}:{:02}:{:02}", hr, mn, sc));
+    assert_eq!(ampm, format!("03:{:02}:{:02} P.M.", mn, sc));
+
+    let noon = spice::lst2et(399, 0.0, "PLANETOCENTRIC", (12, 0, 0), et, 3600.0).unwrap();
+    assert!(noon.0 > et && noon.0 < et + 86400.0);
+    let (hr, mn, sc, _, _) = spice::et2lst(noon, 399, 0.0, "PLANETOCENTRIC");
+    assert_eq!((hr, mn, sc), (12, 0, 0));
+    let (hr, mn, sc, _, _) = spice::et2lst(noon.0 - 0.01, 399, 0.0, "PLANETOCENTRIC");
+    assert_eq!((hr, mn, sc), (11, 59, 59));
+
+    let error = spice::fallible::lst2et(399, 0.0, "PLANETOCENTRIC", (12, 0, 0), et, 0.0);
+    assert_eq!(error.unwrap_err().short, "SPICE(INVALIDSTEP)");
+    let error = spice::fallible::lst2et(399, 0.0, "PLANETOCENTRIC", (25, 0, 0), et, 3600.0);
+    assert_eq!(error.unwrap_err().short, "SPICE(INVALIDTIME)");
+    let error = spice::fallible::lst2et(399, 0.0, "PLANETOCENTRIC", (12, 0, 0), et, 3600.0);
+    assert!(error.is_ok());
+    // A step of a day would skip whole local days
+    let error = spice::fallible::lst2et(399, 0.0, "PLANETOCENTRIC", (12, 0, 0), et, 86400.0);
+    assert_eq!(error.unwrap_err().short, "SPICE(INVALIDSTEP)");
+
+    spice::kclear();
+
+    // Without PCK, the samples fail and the search must stop instead of running forever
+    let error = spice::fallible::lst2et(399, 0.0, "PLANETOCENTRIC", (12, 0, 0), et, 3600.0);
+    assert!(error.is_err());
+    // The samples return the error even with the default `ABORT` action
+    let error = spice::lst2et(399, 0.0, "PLANETOCENTRIC", (12, 0, 0), et, 3600.0);
+    assert!(error.is_err());
+}
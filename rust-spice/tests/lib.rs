@@ -88,10 +88,211 @@ pub mod lock_tests {
 
         let cell = sl.dskobj(&file);
 
-        assert_eq!(cell.card, 1);
-        assert_eq!(cell.get_data_int(0), -658031);
+        assert_eq!(cell.len(), 1);
+        assert_eq!(cell[0], -658031);
 
-        assert_eq!(sl.bodc2n(cell.get_data_int(0)).0, "DIMORPHOS");
+        assert_eq!(sl.bodc2n(cell[0]).0, "DIMORPHOS");
+
+        sl.kclear();
+    }
+    #[test]
+    #[serial]
+    fn cell_set_operations() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+
+        let mut a = spice::Cell::<i32>::new(0);
+        for item in [3, 1, 2, 1] {
+            a.insert(&sl, item);
+        }
+        let mut b = spice::Cell::<i32>::new(2);
+        b.push(&sl, 4);
+        b.push(&sl, 2);
+        b.validate(&sl);
+
+        assert_eq!(Vec::from(a.union(&sl, &b)), vec![1, 2, 3, 4]);
+        assert_eq!(Vec::from(a.intersection(&sl, &b)), vec![2]);
+        assert_eq!(Vec::from(a.difference(&sl, &b)), vec![1, 3]);
+    }
+    #[test]
+    #[serial]
+    fn window_operations() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+
+        let mut a = spice::Window::new(0);
+        a.insert_interval(&sl, 1.0, 3.0);
+        a.insert_interval(&sl, 7.0, 11.0);
+        let mut b = spice::Window::new(1);
+        b.insert_interval(&sl, 2.0, 8.0);
+
+        assert_eq!(Vec::from(a.union(&sl, &b)), vec![(1.0, 11.0)]);
+        assert_eq!(Vec::from(a.intersect(&sl, &b)), vec![(2.0, 3.0), (7.0, 8.0)]);
+        assert_eq!(Vec::from(a.difference(&sl, &b)), vec![(1.0, 2.0), (8.0, 11.0)]);
+
+        a.expand(&sl, 1.0, 1.0);
+        a.fill_gaps(&sl, 2.0);
+        assert_eq!(Vec::from(a.clone()), vec![(0.0, 12.0)]);
+        a.contract(&sl, 1.0, 1.0);
+        a.filter_small(&sl, 5.0);
+        assert_eq!(Vec::from(a), vec![(1.0, 11.0)]);
+    }
+    #[test]
+    #[serial]
+    fn kernels() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.kclear();
+        let path = "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm";
+
+        {
+            let guard = spice::Kernel::load(&sl, path).unwrap();
+            assert!(!guard.children().is_empty());
+            assert_eq!(
+                spice::loaded_kernels(&sl, &spice::KernelKind::ALL).count() as i32,
+                sl.ktotal("ALL")
+            );
+            assert!(spice::LoadedKernel::find(&sl, path).is_some());
+        }
+        assert_eq!(sl.ktotal("ALL"), 0);
+
+        let set = spice::KernelSet::load(&sl, [path]).unwrap();
+        assert_eq!(set.paths().len() as i32, sl.ktotal("ALL"));
+        drop(set);
+        assert_eq!(sl.ktotal("ALL"), 0);
+    }
+    #[test]
+    #[serial]
+    fn pool() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.kclear();
+
+        let watcher = spice::pool::PoolWatcher::new(&sl, &["RUST_SPICE_WATCHED"]).unwrap();
+        assert!(watcher.poll(&sl));
+
+        spice::pool::put_f64s(&sl, "RUST_SPICE_WATCHED", &[1.0, 2.5]).unwrap();
+        assert_eq!(
+            spice::pool::get_f64s(&sl, "RUST_SPICE_WATCHED"),
+            Some(vec![1.0, 2.5])
+        );
+        assert_eq!(
+            spice::pool::names(&sl, "RUST_SPICE_*"),
+            vec!["RUST_SPICE_WATCHED"]
+        );
+        assert_eq!(watcher.changes(&sl).count(), 1);
+        assert_eq!(watcher.changes(&sl).count(), 0);
+
+        sl.kclear();
+    }
+    #[test]
+    #[serial]
+    fn et() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+        let et = spice::Et(sl.str2et("2027-MAR-23 16:00:00"));
+        assert_eq!(et.format(&sl, spice::TIME_FORMAT).unwrap(), "2027-MAR-23 16:00:00");
+        assert_eq!(et.format_default(&sl).unwrap(), "2027-MAR-23 16:00:00");
+        assert_eq!(et.to_string(), "859089669.1856234 TDB seconds past J2000");
+
+        let jd = spice::Et::J2000.julian_date(&sl).unwrap();
+        assert_eq!(jd, 2451545.0);
+        assert_eq!(
+            spice::Et::from_julian_date(&sl, jd).unwrap(),
+            spice::Et::J2000
+        );
+
+        sl.kclear();
+    }
+    #[cfg(feature = "chrono")]
+    #[test]
+    #[serial]
+    fn et_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+        let date = Utc.with_ymd_and_hms(2027, 3, 23, 16, 0, 0).unwrap();
+        let et = spice::Et::from_chrono(&sl, date).unwrap();
+        assert_relative_eq!(et.0, sl.str2et("2027-MAR-23 16:00:00"), epsilon = 1e-6);
+        assert_eq!(et.to_chrono(&sl).unwrap(), date);
+
+        sl.kclear();
+    }
+    #[cfg(feature = "hifitime")]
+    #[test]
+    #[serial]
+    fn et_hifitime() {
+        use hifitime::Epoch;
+
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+        let epoch = Epoch::from_gregorian_utc_hms(2027, 3, 23, 16, 0, 0);
+        let et = spice::Et::from_hifitime(&sl, epoch).unwrap();
+        assert_relative_eq!(et.0, sl.str2et("2027-MAR-23 16:00:00"), epsilon = 1e-6);
+        let back = et.to_hifitime(&sl).unwrap();
+        assert!((back - epoch).abs().to_seconds() < 1e-6);
+
+        sl.kclear();
+    }
+    #[test]
+    #[serial]
+    fn sclk() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+        let hera = spice::Sclk::new(-91);
+        let et = spice::Et(sl.str2et("2027-MAR-23 16:00:00"));
+        let ticks = hera.et_to_ticks(&sl, et).unwrap();
+        assert_relative_eq!(hera.ticks_to_et(&sl, ticks).unwrap().0, et.0, epsilon = 1e-3);
+        assert_eq!(hera.partition_of(&sl, 0.0).unwrap(), Some(1));
+
+        sl.kclear();
+    }
+    #[test]
+    #[serial]
+    fn coordinates() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+
+        let system = spice::Coordinates::Latitudinal;
+        let state = [1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+        let converted = system.state_from_rectangular(&sl, state).unwrap();
+        assert_relative_eq!(converted[5], 1.0, epsilon = 1e-12);
+        let back = system.state_to_rectangular(&sl, converted).unwrap();
+        for (a, b) in back.iter().zip(state.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+        assert_eq!(
+            system.to_rectangular(&sl, [2.0, 0.0, 0.0]).unwrap(),
+            [2.0, 0.0, 0.0]
+        );
+    }
+    #[test]
+    #[serial]
+    fn linalg() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+
+        let v1 = na::Vector3::new(1.0, 0.0, 0.0);
+        let v2 = na::Vector3::new(0.0, 1.0, 0.0);
+        assert_eq!(spice::linalg::vcrss(&sl, &v1, &v2), v1.cross(&v2));
+        assert_eq!(spice::linalg::vdot(&sl, &v1, &v2), 0.0);
+        let matrix = spice::linalg::to_matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(spice::linalg::xpose(&sl, &matrix), matrix.transpose());
+    }
+    #[test]
+    #[serial]
+    fn frame() {
+        let sl = spice::SpiceLock::try_acquire().unwrap();
+        sl.furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+        let j2000 = spice::Frame::from_name(&sl, "j2000").unwrap();
+        assert_eq!(j2000.class(), spice::FrameClass::Inertial);
+        assert_eq!(spice::Frame::from_id(&sl, j2000.id()), Some(j2000.clone()));
+
+        let earth = spice::Frame::body_fixed(&sl, "EARTH").unwrap();
+        assert_eq!(earth.name(), "IAU_EARTH");
+        let et = spice::Et(sl.str2et("2027-MAR-23 16:00:00"));
+        let rotation = j2000.rotation_to(&sl, &earth, et).unwrap();
+        assert_relative_eq!(rotation[(2, 2)], sl.pxform("J2000", "IAU_EARTH", et.0)[2][2]);
 
         sl.kclear();
     }
@@ -127,4 +328,114 @@ pub mod lock_tests {
             .unwrap()
             .unload("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
     }
+    #[test]
+    #[serial]
+    fn service() {
+        use std::thread;
+
+        let service = spice::SpiceService::try_acquire().unwrap();
+        assert!(spice::SpiceLock::try_acquire().is_err());
+
+        let handle = service.handle();
+        handle
+            .call(|sl| {
+                sl.furnsh(
+                    "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm",
+                )
+            })
+            .unwrap();
+
+        let children = (0..5)
+            .map(|_| {
+                let handle = handle.clone();
+                thread::spawn(move || handle.call(|sl| sl.str2et("2027-MAR-23 16:00:00")))
+            })
+            .collect::<Vec<_>>();
+        for c in children {
+            assert_relative_eq!(
+                c.join().unwrap().unwrap(),
+                859089669.1856234,
+                epsilon = f64::EPSILON
+            );
+        }
+
+        // A panicking request is reported to its caller only
+        assert_eq!(
+            handle.call(|_| panic!("request panicked")).unwrap_err(),
+            spice::ServiceError::Panicked
+        );
+        assert!(handle.call(|sl| sl.ktotal("ALL")).unwrap() > 0);
+
+        // Cells and windows are sent back to the caller
+        let window = handle
+            .call(|sl| {
+                let mut window = spice::Window::new(1);
+                window.insert_interval(sl, 1.0, 3.0);
+                window
+            })
+            .unwrap();
+        assert_eq!(Vec::from(window), vec![(1.0, 3.0)]);
+
+        handle.call(|sl| sl.kclear()).unwrap();
+        drop(handle);
+        drop(service.into_lock());
+        assert!(spice::SpiceLock::try_acquire().is_ok());
+    }
+    #[test]
+    #[serial]
+    fn service_async() {
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+        use std::thread::{self, Thread};
+
+        struct ThreadWaker(Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        // Minimal executor polling a future on the current thread
+        fn block_on<F: Future>(future: F) -> F::Output {
+            let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+            let mut cx = Context::from_waker(&waker);
+            let mut future = Box::pin(future);
+            loop {
+                match future.as_mut().poll(&mut cx) {
+                    Poll::Ready(output) => return output,
+                    Poll::Pending => thread::park(),
+                }
+            }
+        }
+
+        let service = spice::SpiceService::try_acquire().unwrap();
+        let handle = service.handle();
+
+        let et = block_on(async {
+            handle
+                .call_async(|sl| {
+                    sl.furnsh(
+                        "/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm",
+                    )
+                })
+                .await
+                .unwrap();
+            handle
+                .call_async(|sl| sl.str2et("2027-MAR-23 16:00:00"))
+                .await
+                .unwrap()
+        });
+        assert_relative_eq!(et, 859089669.1856234, epsilon = f64::EPSILON);
+
+        assert_eq!(
+            block_on(handle.call_async(|_| panic!("request panicked"))).unwrap_err(),
+            spice::ServiceError::Panicked
+        );
+
+        block_on(handle.call_async(|sl| sl.kclear())).unwrap();
+        drop(handle);
+        drop(service.into_lock());
+    }
 }
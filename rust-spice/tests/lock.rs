@@ -0,0 +1,280 @@
+//! Check that every public function of the modules of `src/core` is reachable with the `lock`
+//! feature, either as a method of `SpiceLock` or by taking the `SpiceLock` as a parameter, unless it
+//! does not call CSPICE.
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use syn::{Attribute, ImplItem, Item, ItemFn, Signature, Type, UseTree, Visibility};
+
+/// Modules defining the lock itself.
+const SKIPPED: [&str; 2] = ["lock.rs", "service.rs"];
+
+/// Public functions which do not call CSPICE and need no lock, as `<file> <name>` for functions and
+/// `<file> <type>::<name>` for methods.
+const WITHOUT_CSPICE: [&str; 45] = [
+    "cell.rs Cell::as_mut_ptr",
+    "cell.rs Cell::as_ptr",
+    "cell.rs Cell::capacity",
+    "cell.rs Cell::contains",
+    "cell.rs Cell::get",
+    "cell.rs Cell::get_data_int",
+    "cell.rs Cell::is_empty",
+    "cell.rs Cell::is_set",
+    "cell.rs Cell::iter",
+    "cell.rs Cell::len",
+    "cell.rs Cell::new",
+    "cell.rs Cell::new_int",
+    "cell.rs Cell::reserve",
+    "frame.rs Frame::center",
+    "frame.rs Frame::class",
+    "frame.rs Frame::class_id",
+    "frame.rs Frame::id",
+    "frame.rs Frame::name",
+    "frame.rs FrameClass::code",
+    "frame.rs FrameClass::from_code",
+    "kernel.rs KernelGuard::keep",
+    "kernel.rs KernelGuard::path",
+    "kernel.rs KernelKind::as_str",
+    "kernel.rs KernelSet::is_empty",
+    "kernel.rs KernelSet::iter",
+    "kernel.rs KernelSet::len",
+    "linalg.rs from_matrix",
+    "linalg.rs from_unit_quaternion",
+    "linalg.rs to_matrix",
+    "linalg.rs to_rotation",
+    "linalg.rs to_unit_quaternion",
+    "pool.rs PoolWatcher::agent",
+    "pool.rs PoolWatcher::names",
+    "sclk.rs Sclk::id",
+    "sclk.rs Sclk::new",
+    "window.rs Window::as_cell",
+    "window.rs Window::as_mut_ptr",
+    "window.rs Window::capacity",
+    "window.rs Window::contains",
+    "window.rs Window::get",
+    "window.rs Window::is_empty",
+    "window.rs Window::iter",
+    "window.rs Window::len",
+    "window.rs Window::measure",
+    "window.rs Window::new",
+];
+
+/// Public methods calling CSPICE on values borrowing the `SpiceLock`, as `<file> <type>::<name>`.
+const BORROWING_LOCK: [&str; 3] = [
+    "kernel.rs KernelGuard::children",
+    "kernel.rs KernelSet::paths",
+    "kernel.rs KernelSet::push",
+];
+
+/// Public functions calling CSPICE which are only used by the crate itself with the lock, as
+/// `<file> <name>`.
+const NOT_EXPORTED: [&str; 2] = ["error.rs take_error", "error.rs try_call"];
+
+/// Tokens of an attribute or a signature as a string, without whitespace.
+fn compact(tokens: &impl ToTokens) -> String {
+    tokens.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Whether the attributes remove the item with the lock.
+fn without_lock(attributes: &[Attribute]) -> bool {
+    attributes
+        .iter()
+        .any(|attribute| compact(attribute).contains("cfg(not(feature=\"lock\"))"))
+}
+
+/// Whether the attributes or the signature of a public function make it reachable with the lock.
+fn takes_lock(attributes: &[Attribute], signature: &Signature) -> bool {
+    compact(signature).contains("SpiceLock")
+        || without_lock(attributes)
+        || attributes.iter().map(compact).any(|attribute| {
+            attribute.contains("impl_for(SpiceLock") || attribute.starts_with("#[with_lock")
+        })
+}
+
+/// Public functions found in the sources, as `<file> <name>` or `<file> <type>::<name>`, with
+/// whether they are reachable with the lock.
+type Functions = BTreeMap<String, bool>;
+
+/// Public functions re-exported under another path, as `<file> <name>`, with the function they
+/// re-export.
+type Reexports = BTreeMap<String, String>;
+
+/// Add a public function declared in a file, along with its fallible variant generated by
+/// `cspice_proc!`, if any.
+fn add_function(file: &str, function: &ItemFn, functions: &mut Functions) {
+    if !matches!(function.vis, Visibility::Public(_)) {
+        return;
+    }
+    let name = &function.sig.ident;
+    let reachable = takes_lock(&function.attrs, &function.sig);
+    functions.insert(format!("{} {}", file, name), reachable);
+    if function
+        .attrs
+        .iter()
+        .any(|attribute| attribute.path.is_ident("fallible"))
+    {
+        functions.insert(format!("{} try_{}", file, name), reachable);
+    }
+}
+
+/// Replace the metavariables of a `macro_rules!` transcriber by a placeholder identifier, so that
+/// the items it expands to can be parsed.
+fn replace_metavariables(tokens: TokenStream) -> TokenStream {
+    let mut replaced = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '$' => {
+                if let Some(TokenTree::Ident(_)) = tokens.peek() {
+                    tokens.next();
+                    replaced.push(TokenTree::Ident(Ident::new(
+                        "Placeholder",
+                        Span::call_site(),
+                    )));
+                } else {
+                    replaced.push(TokenTree::Punct(punct));
+                }
+            }
+            TokenTree::Group(group) => replaced.push(TokenTree::Group(proc_macro2::Group::new(
+                group.delimiter(),
+                replace_metavariables(group.stream()),
+            ))),
+            token => replaced.push(token),
+        }
+    }
+    replaced.into_iter().collect()
+}
+
+/// Items expanded by the rules of a `macro_rules!` whose transcribers are items.
+fn macro_rules_items(tokens: TokenStream) -> Vec<Item> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    tokens
+        .windows(3)
+        .filter_map(|window| match window {
+            [TokenTree::Punct(eq), TokenTree::Punct(gt), TokenTree::Group(transcriber)]
+                if eq.as_char() == '=' && gt.as_char() == '>' =>
+            {
+                syn::parse2::<syn::File>(replace_metavariables(transcriber.stream())).ok()
+            }
+            _ => None,
+        })
+        .flat_map(|file| file.items)
+        .collect()
+}
+
+/// Add the public re-exports of a use tree, given the path leading to it.
+fn add_reexports(file: &str, module: &str, tree: &UseTree, reexports: &mut Reexports) {
+    match tree {
+        UseTree::Path(path) => add_reexports(file, &path.ident.to_string(), &path.tree, reexports),
+        UseTree::Group(group) => group
+            .items
+            .iter()
+            .for_each(|tree| add_reexports(file, module, tree, reexports)),
+        UseTree::Name(name) => {
+            let target = format!("{}.rs {}", module, name.ident);
+            reexports.insert(format!("{} {}", file, name.ident), target);
+        }
+        UseTree::Rename(rename) => {
+            let target = format!("{}.rs {}", module, rename.ident);
+            reexports.insert(format!("{} {}", file, rename.rename), target);
+        }
+        UseTree::Glob(_) => {}
+    }
+}
+
+/// Collect the public functions and re-exports of the items of a source file.
+fn collect(file: &str, items: &[Item], functions: &mut Functions, reexports: &mut Reexports) {
+    for item in items {
+        match item {
+            Item::Fn(function) => add_function(file, function, functions),
+            Item::Impl(block) if block.trait_.is_none() && !without_lock(&block.attrs) => {
+                let name = match &*block.self_ty {
+                    Type::Path(path) => path.path.segments.last().unwrap().ident.to_string(),
+                    other => compact(other),
+                };
+                for method in &block.items {
+                    match method {
+                        ImplItem::Method(method) if matches!(method.vis, Visibility::Public(_)) => {
+                            let reachable = takes_lock(&method.attrs, &method.sig);
+                            let function = format!("{} {}::{}", file, name, method.sig.ident);
+                            functions.insert(function, reachable);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Item::Macro(item) if item.mac.path.is_ident("cspice_proc") => {
+                let function = item.mac.parse_body::<ItemFn>().unwrap();
+                add_function(file, &function, functions);
+            }
+            Item::Macro(item) if item.mac.path.is_ident("macro_rules") => {
+                let expanded = macro_rules_items(item.mac.tokens.clone());
+                collect(file, &expanded, functions, reexports);
+            }
+            Item::Use(item) if matches!(item.vis, Visibility::Public(_)) => {
+                add_reexports(file, "", &item.tree, reexports);
+            }
+            Item::Mod(module) if !without_lock(&module.attrs) => {
+                if let Some((_, items)) = &module.content {
+                    collect(file, items, functions, reexports);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn every_function_takes_the_lock() {
+    let core = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/core");
+    let mut paths = fs::read_dir(core)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .filter(|path| !SKIPPED.iter().any(|skipped| path.ends_with(skipped)))
+        .collect::<Vec<_>>();
+    paths.sort();
+    let mut functions = Functions::new();
+    let mut reexports = Reexports::new();
+    for path in &paths {
+        let file = path.file_name().unwrap().to_str().unwrap();
+        let source = syn::parse_file(&fs::read_to_string(path).unwrap()).unwrap();
+        collect(file, &source.items, &mut functions, &mut reexports);
+    }
+
+    // A re-export is only reachable with the lock if the function it re-exports is, the
+    // re-exports of types and constants are left out
+    let exempt = [&WITHOUT_CSPICE[..], &BORROWING_LOCK, &NOT_EXPORTED].concat();
+    let found = functions
+        .iter()
+        .map(|(function, reachable)| (function.as_str(), function.as_str(), *reachable))
+        .chain(reexports.iter().filter_map(|(reexport, target)| {
+            let reachable = *functions.get(target)?;
+            Some((reexport.as_str(), target.as_str(), reachable))
+        }))
+        .filter(|(_, _, reachable)| !reachable)
+        .collect::<Vec<_>>();
+    let missing = found
+        .iter()
+        .filter(|(_, target, _)| !exempt.contains(target))
+        .map(|(function, _, _)| *function)
+        .collect::<Vec<_>>();
+    assert!(
+        missing.is_empty(),
+        "public functions neither taking the SpiceLock nor exposed on it:\n{}",
+        missing.join("\n")
+    );
+
+    let stale = exempt
+        .iter()
+        .filter(|function| !found.iter().any(|(found, _, _)| found == *function))
+        .collect::<Vec<_>>();
+    assert!(
+        stale.is_empty(),
+        "exempted functions not found or taking the lock: {:?}",
+        stale
+    );
+}
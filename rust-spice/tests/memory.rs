@@ -0,0 +1,48 @@
+#![cfg(not(feature = "lock"))]
+
+extern crate spice;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator keeping track of the number of bytes currently allocated
+struct Counter;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+#[test]
+fn no_growth_under_repeated_calls() {
+    spice::furnsh("/Users/gregoireh/data/spice-kernels/hera/kernels/mk/hera_study_PO_EMA_2024.tm");
+
+    let before = ALLOCATED.load(Ordering::SeqCst);
+
+    for _ in 0..10_000 {
+        let et = spice::str2et("2027-MAR-23 16:00:00");
+        let (_position, _light_time) = spice::spkpos("DIMORPHOS", et, "J2000", "NONE", "HERA");
+        let _date = spice::timout(et, spice::TIME_FORMAT);
+        let (_name, _found) = spice::bodc2n(-658031);
+        let (file, _filtyp, _source, _handle, _found) = spice::kdata(1, "dsk");
+        let _cell = spice::dskobj(&file);
+    }
+
+    let after = ALLOCATED.load(Ordering::SeqCst);
+
+    assert_eq!(before, after);
+
+    spice::kclear();
+}